@@ -0,0 +1,158 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Calendar aware arithmetic.
+//!
+//! 'add_days', 'add_months' and 'add_years' move the date and keep the wall clock time,
+//! so "tomorrow at 09:00" is still 09:00 after a DST change. 'add_duration' adds exact
+//! elapsed time instead. the result is converted back to the zone of 'self' (see 'Zone')
+//! which re-derives 'week_day', 'hour_12', 'day_of_year' and the offset.
+
+use std::time::Duration;
+
+use crate::{ReadableTime, ReadableTimeError, civil, time_t};
+
+/// What to do when the day does not exist in the target month, like Jan 31 + 1 month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonthOverflow {
+    /// use the last day of the month. Jan 31 + 1 month is Feb 28 (29 in a leap year)
+    #[default]
+    Clamp,
+    /// carry the extra days into the next month. Jan 31 + 1 month is Mar 3 (Mar 2 in a leap year)
+    Overflow,
+    /// return an error
+    Error,
+}
+
+impl ReadableTime {
+    /// same wall clock time 'days' days later. negative goes back
+    pub fn add_days(&self, days: i64) -> Result<ReadableTime, ReadableTimeError> {
+        let start = civil::days_from_civil(self.year as i64, self.month.number(), self.day as u32);
+        let days = start
+            .checked_add(days)
+            .ok_or(ReadableTimeError::OutOfRange)?;
+        self.with_days(days)
+    }
+
+    pub fn sub_days(&self, days: i64) -> Result<ReadableTime, ReadableTimeError> {
+        self.add_days(days.checked_neg().ok_or(ReadableTimeError::OutOfRange)?)
+    }
+
+    /// same day and wall clock time 'months' months later, with 'MonthOverflow::Clamp'
+    /// EXAMPLE: 2025-01-31 + 1 month is 2025-02-28
+    pub fn add_months(&self, months: i64) -> Result<ReadableTime, ReadableTimeError> {
+        self.add_months_with(months, MonthOverflow::Clamp)
+    }
+
+    pub fn sub_months(&self, months: i64) -> Result<ReadableTime, ReadableTimeError> {
+        self.add_months(months.checked_neg().ok_or(ReadableTimeError::OutOfRange)?)
+    }
+
+    /// same as 'add_months' with a chosen policy for days missing in the target month
+    pub fn add_months_with(
+        &self,
+        months: i64,
+        overflow: MonthOverflow,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        let days = shift_months(
+            self.year as i64,
+            self.month.number(),
+            self.day as u32,
+            months,
+            overflow,
+        )?;
+        self.with_days(days)
+    }
+
+    /// 'add_months' with 12 * 'years'. Feb 29 + 1 year is Feb 28
+    pub fn add_years(&self, years: i64) -> Result<ReadableTime, ReadableTimeError> {
+        self.add_years_with(years, MonthOverflow::Clamp)
+    }
+
+    pub fn sub_years(&self, years: i64) -> Result<ReadableTime, ReadableTimeError> {
+        self.add_years(years.checked_neg().ok_or(ReadableTimeError::OutOfRange)?)
+    }
+
+    pub fn add_years_with(
+        &self,
+        years: i64,
+        overflow: MonthOverflow,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        let months = years.checked_mul(12).ok_or(ReadableTimeError::OutOfRange)?;
+        self.add_months_with(months, overflow)
+    }
+
+    /// exact elapsed time later. the wall clock may move by more or less than 'd' over a DST
+    /// change
+    pub fn add_duration(&self, d: Duration) -> Result<ReadableTime, ReadableTimeError> {
+        let secs = i64::try_from(d.as_secs()).map_err(|_| ReadableTimeError::DurationTooLarge)?;
+        self.add_exact(secs, d.subsec_nanos() as i64)
+    }
+
+    pub fn sub_duration(&self, d: Duration) -> Result<ReadableTime, ReadableTimeError> {
+        let secs = i64::try_from(d.as_secs()).map_err(|_| ReadableTimeError::DurationTooLarge)?;
+        self.add_exact(-secs, -(d.subsec_nanos() as i64))
+    }
+
+    /// 'nanos' is within one second either way
+    fn add_exact(&self, secs: i64, nanos: i64) -> Result<ReadableTime, ReadableTimeError> {
+        let total = self.nanosecond as i64 + nanos;
+        let timestamp = self
+            .unix_timestamp()
+            .checked_add(secs)
+            .and_then(|t| t.checked_add(total.div_euclid(1_000_000_000)))
+            .and_then(|t| time_t::try_from(t).ok())
+            .ok_or(ReadableTimeError::OutOfRange)?;
+        let mut rt = self.zone.at(timestamp)?;
+        rt.nanosecond = total.rem_euclid(1_000_000_000) as u32;
+        Ok(rt)
+    }
+
+    /// 'days' since 1970-01-01 with the time of day of 'self', converted in 'self.zone'
+    fn with_days(&self, days: i64) -> Result<ReadableTime, ReadableTimeError> {
+        let secs = (self.hour_24 * 3600 + self.minute * 60 + self.second) as i64;
+        let local = days
+            .checked_mul(civil::SECONDS_PER_DAY)
+            .and_then(|l| l.checked_add(secs))
+            .ok_or(ReadableTimeError::OutOfRange)?;
+        let mut rt = self.zone.at(self.zone.resolve(local)?)?;
+        rt.nanosecond = self.nanosecond;
+        Ok(rt)
+    }
+}
+
+/// days since 1970-01-01 of the date 'months' months after year-month-day
+pub(crate) fn shift_months(
+    year: i64,
+    month: u32,
+    day: u32,
+    months: i64,
+    overflow: MonthOverflow,
+) -> Result<i64, ReadableTimeError> {
+    let total = (year * 12 + month as i64 - 1)
+        .checked_add(months)
+        .ok_or(ReadableTimeError::OutOfRange)?;
+    let year = total.div_euclid(12);
+    let month = total.rem_euclid(12) as u32 + 1;
+    // 'ReadableTime' and 'Date' keep the year in an i32
+    if i32::try_from(year).is_err() {
+        return Err(ReadableTimeError::OutOfRange);
+    }
+    let last = civil::days_in_month(year, month);
+
+    if day <= last {
+        return Ok(civil::days_from_civil(year, month, day));
+    }
+    match overflow {
+        MonthOverflow::Clamp => Ok(civil::days_from_civil(year, month, last)),
+        MonthOverflow::Overflow => {
+            Ok(civil::days_from_civil(year, month, last) + (day - last) as i64)
+        }
+        MonthOverflow::Error => Err(ReadableTimeError::InvalidDate { year, month, day }),
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Month, quarter and year grids like cal(1).
+
+use crate::{Locale, Month, Weekday, civil};
+
+/// reverse video, what cal(1) uses for today
+const ANSI_START: &str = "\x1b[7m";
+const ANSI_END: &str = "\x1b[0m";
+
+/// space between months printed side by side
+const GAP: &str = "  ";
+
+/// How 'Calendar::today' is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalendarStyle {
+    /// no highlight, safe for files and pipes
+    #[default]
+    Plain,
+    /// today in reverse video
+    Ansi,
+}
+
+/// Options for printing calendars. build with struct syntax on top of 'Default':
+///
+/// ```
+/// # use readable_time::*;
+/// let cal = Calendar {
+///     first_weekday: Weekday::Monday,
+///     week_numbers: true,
+///     ..Calendar::default()
+/// };
+/// print!("{}", cal.month(2025, Month::November));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calendar {
+    /// first column of the grid. default sunday like cal(1)
+    pub first_weekday: Weekday,
+    /// ISO 8601 week number in front of each row, taken from the monday of the row
+    pub week_numbers: bool,
+    /// month names and weekday headers
+    pub locale: Locale,
+    pub style: CalendarStyle,
+    /// (year, month, day) to highlight. only shown with 'CalendarStyle::Ansi'
+    /// EXAMPLE: `Some((rt.year, rt.month, rt.day))`
+    pub today: Option<(i32, Month, i32)>,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Calendar {
+            first_weekday: Weekday::Sunday,
+            week_numbers: false,
+            locale: Locale::EN_US,
+            style: CalendarStyle::Plain,
+            today: None,
+        }
+    }
+}
+
+impl Calendar {
+    /// one month with the year in the title
+    /// EXAMPLE:
+    /// ```text
+    ///    November 2025
+    /// Su Mo Tu We Th Fr Sa
+    ///                    1
+    ///  2  3  4  5  6  7  8
+    /// ```
+    pub fn month(&self, year: i32, month: Month) -> String {
+        join_lines(&[self.month_block(year, month, true)])
+    }
+
+    /// the three months of the quarter containing 'month' side by side
+    /// EXAMPLE: 'Month::November' prints october to december
+    pub fn quarter(&self, year: i32, month: Month) -> String {
+        let first = (month.to_c() / 3) * 3 + 1;
+        let blocks: Vec<Vec<String>> = (first..first + 3)
+            .filter_map(Month::from_number)
+            .map(|m| self.month_block(year, m, true))
+            .collect();
+        join_lines(&blocks)
+    }
+
+    /// the whole year, three months per row with the year centered on top
+    pub fn year(&self, year: i32) -> String {
+        let width = self.block_width() * 3 + GAP.len() * 2;
+        let mut out = String::new();
+        out.push_str(center(&year.to_string(), width).trim_end());
+        out.push('\n');
+        for row in Month::ALL.chunks(3) {
+            out.push('\n');
+            let blocks: Vec<Vec<String>> = row
+                .iter()
+                .map(|m| self.month_block(year, *m, false))
+                .collect();
+            out.push_str(&join_lines(&blocks));
+        }
+        out
+    }
+
+    fn block_width(&self) -> usize {
+        if self.week_numbers { 23 } else { 20 }
+    }
+
+    /// title, weekday header and 6 rows, every line padded to 'block_width'
+    fn month_block(&self, year: i32, month: Month, with_year: bool) -> Vec<String> {
+        let width = self.block_width();
+        let mut lines = Vec::with_capacity(8);
+
+        let name = self.locale.month(month);
+        let title = if with_year {
+            format!("{name} {year}")
+        } else {
+            name.to_string()
+        };
+        lines.push(center(&title, width));
+
+        let mut header = String::new();
+        if self.week_numbers {
+            header.push_str("   ");
+        }
+        for i in 0..7 {
+            let day = self.first_weekday + i as i64;
+            if i > 0 {
+                header.push(' ');
+            }
+            header.push_str(&fit(self.locale.weekday_short(day), 2));
+        }
+        lines.push(header);
+
+        let year64 = year as i64;
+        let first = civil::days_from_civil(year64, month.number(), 1);
+        let last = month.days(year64) as i64;
+        let lead = (civil::weekday_from_days(first) + 7 - self.first_weekday.to_c()) % 7;
+        // days since epoch of the first cell
+        let grid = first - lead as i64;
+
+        for row in 0..6 {
+            let row_start = grid + row * 7;
+            let mut line = String::new();
+            let in_month = |d: i64| (1..=last).contains(&(d - first + 1));
+            if self.week_numbers {
+                if (0..7).any(|i| in_month(row_start + i)) {
+                    let monday = row_start + Weekday::Monday.days_since(self.first_weekday) as i64;
+                    let (_, week, _) = civil::iso_week_from_days(monday);
+                    line.push_str(&format!("{week:>2} "));
+                } else {
+                    line.push_str("   ");
+                }
+            }
+            for i in 0..7 {
+                if i > 0 {
+                    line.push(' ');
+                }
+                let cell = row_start + i;
+                if !in_month(cell) {
+                    line.push_str("  ");
+                    continue;
+                }
+                let day = (cell - first + 1) as i32;
+                let highlight =
+                    self.style == CalendarStyle::Ansi && self.today == Some((year, month, day));
+                if highlight {
+                    line.push_str(&format!("{ANSI_START}{day:>2}{ANSI_END}"));
+                } else {
+                    line.push_str(&format!("{day:>2}"));
+                }
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// months side by side separated by 'GAP', trailing spaces removed
+fn join_lines(blocks: &[Vec<String>]) -> String {
+    let rows = blocks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for r in 0..rows {
+        let line: Vec<&str> = blocks.iter().map(|b| b[r].as_str()).collect();
+        out.push_str(line.join(GAP).trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// 's' centered in 'width' columns, extra space goes on the right like cal(1)
+fn center(s: &str, width: usize) -> String {
+    let w = display_width(s);
+    let left = width.saturating_sub(w) / 2;
+    let right = width.saturating_sub(w + left);
+    format!("{}{s}{}", " ".repeat(left), " ".repeat(right))
+}
+
+/// cut 's' to at most 'width' columns and pad it to exactly that
+fn fit(s: &str, width: usize) -> String {
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = char_width(c);
+        if used + w > width {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    out.push_str(&" ".repeat(width - used));
+    out
+}
+
+fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// terminal columns of 'c'. good enough for the names in the built in locales:
+/// CJK is 2 columns wide, combining marks (like devanagari vowel signs) take none
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        // combining marks
+        0x0300..=0x036F
+        | 0x0900..=0x0903
+        | 0x093A..=0x094F
+        | 0x0951..=0x0957
+        | 0x0962..=0x0963
+        | 0x200B..=0x200F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6 => 2,
+        _ => 1,
+    }
+}
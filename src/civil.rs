@@ -0,0 +1,96 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Pure rust proleptic gregorian calendar math. no libc involved.
+//!
+//! Days are counted from the unix epoch (1970-01-01 is day 0).
+//! Algorithms are from Howard Hinnant's 'chrono-Compatible Low-Level Date Algorithms'.
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// true if 'year' has a 29th of february
+pub fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// number of days in 'month' (1-12) of 'year'. returns 0 for invalid months
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// days since 1970-01-01 for the given date. 'month' is 1-12 and 'day' 1-31.
+/// out of range 'day' values are not checked and just roll into the next month
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// inverse of 'days_from_civil'. returns (year, month 1-12, day 1-31)
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// day of week for days since epoch. 0 = Sunday .. 6 = Saturday (same as 'tm_wday')
+pub fn weekday_from_days(days: i64) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days + 4).rem_euclid(7) as u32
+}
+
+/// day of year. 1 = january 1st
+pub fn day_of_year(year: i64, month: u32, day: u32) -> u32 {
+    (days_from_civil(year, month, day) - days_from_civil(year, 1, 1)) as u32 + 1
+}
+
+/// number of ISO 8601 weeks in 'year', 52 or 53
+pub fn iso_weeks_in_year(year: i64) -> u32 {
+    let p = |y: i64| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)).rem_euclid(7);
+    if p(year) == 4 || p(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// ISO 8601 week date for days since epoch. returns (iso year, week 1-53, weekday 1-7 monday first).
+/// the iso year differs from the calendar year for a few days around january 1st
+pub fn iso_week_from_days(days: i64) -> (i64, u32, u32) {
+    let weekday = (weekday_from_days(days) + 6) % 7 + 1;
+    // the thursday of the same week decides the year
+    let thursday = days - weekday as i64 + 4;
+    let (year, _, _) = civil_from_days(thursday);
+    let week = (thursday - days_from_civil(year, 1, 1)) / 7 + 1;
+    (year, week as u32, weekday)
+}
+
+/// inverse of 'iso_week_from_days'. 'week' and 'weekday' are not checked and roll over
+pub fn days_from_iso_week(year: i64, week: u32, weekday: u32) -> i64 {
+    // january 4th is always in week 1
+    let jan4 = days_from_civil(year, 1, 4);
+    let week1_monday = jan4 - (weekday_from_days(jan4) as i64 + 6) % 7;
+    week1_monday + (week as i64 - 1) * 7 + weekday as i64 - 1
+}
@@ -0,0 +1,77 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Equality, ordering and hashing.
+//!
+//! the std traits compare the instant (down to the nanosecond), so 07:14 +0545 and 01:29 UTC
+//! on the same day are equal and sort together. use 'cmp_civil' to order by the wall clock
+//! fields instead.
+
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{Month, ReadableTime};
+
+impl PartialEq for ReadableTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for ReadableTime {}
+
+impl PartialOrd for ReadableTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ReadableTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+/// hashes the instant only, so it agrees with 'PartialEq'
+impl Hash for ReadableTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
+}
+
+impl ReadableTime {
+    /// Compare the wall clock fields (date, then time) and ignore the zone.
+    /// EXAMPLE: 09:00 in New York (14:00 UTC) is before 10:00 in Kathmandu (04:15 UTC) on the
+    /// same date, even though it happens later
+    pub fn cmp_civil(&self, other: &ReadableTime) -> Ordering {
+        self.civil_key().cmp(&other.civil_key())
+    }
+
+    /// true if both show the same date and time on the wall clock
+    pub fn eq_civil(&self, other: &ReadableTime) -> bool {
+        self.civil_key() == other.civil_key()
+    }
+
+    fn civil_key(&self) -> (i32, Month, i32, i32, i32, i32, u32) {
+        (
+            self.year,
+            self.month,
+            self.day,
+            self.hour_24,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        )
+    }
+
+    fn instant(&self) -> (i64, u32) {
+        (self.unix_timestamp(), self.nanosecond)
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! A calendar date without time of day or zone.
+
+use std::fmt;
+
+use crate::{
+    Month, ReadableTime, ReadableTimeError, Weekday, Zone,
+    arith::{MonthOverflow, shift_months},
+    civil,
+};
+
+/// A day in the proleptic gregorian calendar. orders by year, then month, then day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: Month,
+    /// 1-31
+    pub day: i32,
+}
+
+impl Date {
+    /// first day of year 'i32::MIN'
+    pub const MIN: Date = Date {
+        year: i32::MIN,
+        month: Month::January,
+        day: 1,
+    };
+
+    /// last day of year 'i32::MAX'
+    pub const MAX: Date = Date {
+        year: i32::MAX,
+        month: Month::December,
+        day: 31,
+    };
+
+    /// returns 'InvalidDate' if the month has no such day
+    pub fn new(year: i32, month: Month, day: i32) -> Result<Date, ReadableTimeError> {
+        if day < 1 || day > month.days(year as i64) as i32 {
+            return Err(ReadableTimeError::InvalidDate {
+                year: year as i64,
+                month: month.number(),
+                day: day.max(0) as u32,
+            });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    /// date 'days' days after 1970-01-01. returns 'OutOfRange' if the year does not fit in an i32
+    pub fn from_days(days: i64) -> Result<Date, ReadableTimeError> {
+        if !(Date::MIN.to_days()..=Date::MAX.to_days()).contains(&days) {
+            return Err(ReadableTimeError::OutOfRange);
+        }
+        let (year, month, day) = civil::civil_from_days(days);
+        Ok(Date {
+            year: year as i32,
+            month: Month::from_number(month).ok_or(ReadableTimeError::OutOfRange)?,
+            day: day as i32,
+        })
+    }
+
+    /// days since 1970-01-01. negative before it
+    pub fn to_days(self) -> i64 {
+        civil::days_from_civil(self.year as i64, self.month.number(), self.day as u32)
+    }
+
+    pub fn week_day(self) -> Weekday {
+        Weekday::from_c(civil::weekday_from_days(self.to_days())).unwrap_or(Weekday::Sunday)
+    }
+
+    /// 1-366
+    pub fn day_of_year(self) -> i32 {
+        civil::day_of_year(self.year as i64, self.month.number(), self.day as u32) as i32
+    }
+
+    /// negative goes back
+    pub fn add_days(self, days: i64) -> Result<Date, ReadableTimeError> {
+        Date::from_days(
+            self.to_days()
+                .checked_add(days)
+                .ok_or(ReadableTimeError::OutOfRange)?,
+        )
+    }
+
+    /// same day 'months' months later, the last day of the month if it is shorter.
+    /// EXAMPLE: 2025-01-31 + 1 month is 2025-02-28
+    pub fn add_months(self, months: i64) -> Result<Date, ReadableTimeError> {
+        self.add_months_with(months, MonthOverflow::Clamp)
+    }
+
+    pub fn add_months_with(
+        self,
+        months: i64,
+        overflow: MonthOverflow,
+    ) -> Result<Date, ReadableTimeError> {
+        Date::from_days(shift_months(
+            self.year as i64,
+            self.month.number(),
+            self.day as u32,
+            months,
+            overflow,
+        )?)
+    }
+
+    /// first instant of the date in 'zone'. if midnight is skipped by a DST change
+    /// the first time after the gap is used
+    pub fn start_of_day(self, zone: &Zone) -> Result<ReadableTime, ReadableTimeError> {
+        let local = self
+            .to_days()
+            .checked_mul(civil::SECONDS_PER_DAY)
+            .ok_or(ReadableTimeError::OutOfRange)?;
+        zone.at(zone.resolve(local)?)
+    }
+}
+
+impl ReadableTime {
+    /// the calendar date of 'self' in its own zone
+    pub fn date(&self) -> Date {
+        Date {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+        }
+    }
+}
+
+/// "2025-11-30"
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year,
+            self.month.number(),
+            self.day
+        )
+    }
+}
@@ -0,0 +1,366 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Human readable 'std::time::Duration' like "1h 2m 3s" or "1 hour, 2 minutes",
+//! and the parser for the same forms.
+
+use std::time::Duration;
+
+use crate::{ReadableTimeError, parse::Parser};
+
+/// units from nanoseconds to years. a year is 365 days and a week 7 days
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Year,
+}
+
+impl TimeUnit {
+    /// biggest first
+    pub const ALL: [TimeUnit; 9] = [
+        TimeUnit::Year,
+        TimeUnit::Week,
+        TimeUnit::Day,
+        TimeUnit::Hour,
+        TimeUnit::Minute,
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+        TimeUnit::Microsecond,
+        TimeUnit::Nanosecond,
+    ];
+
+    pub fn nanos(self) -> u128 {
+        const S: u128 = 1_000_000_000;
+        match self {
+            TimeUnit::Nanosecond => 1,
+            TimeUnit::Microsecond => 1_000,
+            TimeUnit::Millisecond => 1_000_000,
+            TimeUnit::Second => S,
+            TimeUnit::Minute => 60 * S,
+            TimeUnit::Hour => 3600 * S,
+            TimeUnit::Day => 86_400 * S,
+            TimeUnit::Week => 7 * 86_400 * S,
+            TimeUnit::Year => 365 * 86_400 * S,
+        }
+    }
+
+    /// 'ns', 'µs', 'ms', 's', 'm', 'h', 'd', 'w', 'y'
+    pub fn short_name(self) -> &'static str {
+        match self {
+            TimeUnit::Nanosecond => "ns",
+            TimeUnit::Microsecond => "µs",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "m",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "d",
+            TimeUnit::Week => "w",
+            TimeUnit::Year => "y",
+        }
+    }
+
+    /// singular name like 'second'
+    pub fn long_name(self) -> &'static str {
+        match self {
+            TimeUnit::Nanosecond => "nanosecond",
+            TimeUnit::Microsecond => "microsecond",
+            TimeUnit::Millisecond => "millisecond",
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Year => "year",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DurationStyle {
+    /// "1h 2m 3s"
+    #[default]
+    Short,
+    /// "1 hour, 2 minutes, 3 seconds"
+    Long,
+    /// "01:02:03". fractional digits follow 'smallest' ("01:02:03.500" for milliseconds)
+    Clock,
+}
+
+/// Options for formatting a 'Duration'. anything below 'smallest' is truncated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormat {
+    pub style: DurationStyle,
+    /// biggest unit used. bigger amounts are shown in this unit ("90m" with 'Minute')
+    pub largest: TimeUnit,
+    pub smallest: TimeUnit,
+    /// show at most this many non zero units, starting from the biggest. 0 counts as 1
+    pub precision: Option<usize>,
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        DurationFormat {
+            style: DurationStyle::Short,
+            largest: TimeUnit::Year,
+            smallest: TimeUnit::Nanosecond,
+            precision: None,
+        }
+    }
+}
+
+/// format with the default options
+/// EXAMPLE: Duration::from_secs_f64(3723.5) gives "1h 2m 3s 500ms"
+pub fn format_duration(d: Duration) -> String {
+    DurationFormat::default().format(d)
+}
+
+impl DurationFormat {
+    pub fn format(&self, d: Duration) -> String {
+        if self.style == DurationStyle::Clock {
+            return self.clock(d);
+        }
+
+        let (largest, smallest) = if self.largest < self.smallest {
+            (self.smallest, self.smallest)
+        } else {
+            (self.largest, self.smallest)
+        };
+        let mut rest = d.as_nanos();
+        let mut parts = Vec::new();
+        for unit in TimeUnit::ALL {
+            if unit > largest || unit < smallest {
+                continue;
+            }
+            if self.precision.is_some_and(|p| parts.len() >= p.max(1)) {
+                break;
+            }
+            let count = rest / unit.nanos();
+            rest %= unit.nanos();
+            if count > 0 {
+                parts.push(self.part(count, unit));
+            }
+        }
+
+        if parts.is_empty() {
+            let unit = smallest.max(TimeUnit::Second).min(largest);
+            return self.part(0, unit);
+        }
+        match self.style {
+            DurationStyle::Long => parts.join(", "),
+            _ => parts.join(" "),
+        }
+    }
+
+    fn part(&self, count: u128, unit: TimeUnit) -> String {
+        match self.style {
+            DurationStyle::Long => {
+                let plural = if count == 1 { "" } else { "s" };
+                format!("{count} {}{plural}", unit.long_name())
+            }
+            _ => format!("{count}{}", unit.short_name()),
+        }
+    }
+
+    /// 'H:MM:SS', 'M:SS' or 'S' depending on 'largest', hours are zero padded to 2 digits
+    fn clock(&self, d: Duration) -> String {
+        let secs = d.as_secs();
+        let mut out = match self.largest {
+            TimeUnit::Nanosecond
+            | TimeUnit::Microsecond
+            | TimeUnit::Millisecond
+            | TimeUnit::Second => format!("{secs:02}"),
+            TimeUnit::Minute => format!("{:02}:{:02}", secs / 60, secs % 60),
+            _ => format!(
+                "{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs % 3600 / 60,
+                secs % 60
+            ),
+        };
+        let digits = match self.smallest {
+            TimeUnit::Nanosecond => 9,
+            TimeUnit::Microsecond => 6,
+            TimeUnit::Millisecond => 3,
+            _ => 0,
+        };
+        if digits > 0 {
+            let frac = format!("{:09}", d.subsec_nanos());
+            out.push('.');
+            out.push_str(&frac[..digits]);
+        }
+        out
+    }
+}
+
+/// Parse durations written by people or by 'DurationFormat':
+///
+/// - compact: '1h30m15s', '250ms', '1.5h'
+/// - verbose: '2 days, 4 hours', '1 hour and 30 minutes'
+/// - clock: '01:02:03', '01:02:03.500', '62:03' (minutes and seconds)
+///
+/// units can be short ('h'), abbreviated ('hr', 'hrs', 'min', 'sec') or full ('hours').
+/// a year is 365 days and a week 7 days, same as the formatter
+pub fn parse_duration(input: &str) -> Result<Duration, ReadableTimeError> {
+    let mut p = Parser { input, pos: 0 };
+    p.skip_whitespace();
+    if p.rest().is_empty() {
+        return Err(p.error("duration"));
+    }
+    let total = if input.contains(':') {
+        clock(&mut p)?
+    } else {
+        terms(&mut p)?
+    };
+    p.skip_whitespace();
+    if !p.rest().is_empty() {
+        return Err(p.error("end of input"));
+    }
+
+    let secs =
+        u64::try_from(total / 1_000_000_000).map_err(|_| ReadableTimeError::DurationTooLarge)?;
+    Ok(Duration::new(secs, (total % 1_000_000_000) as u32))
+}
+
+fn terms(p: &mut Parser) -> Result<u128, ReadableTimeError> {
+    let mut total: u128 = 0;
+    let mut first = true;
+    loop {
+        if !first {
+            let before = p.pos;
+            p.skip_whitespace();
+            if p.rest().starts_with(',') {
+                p.pos += 1;
+                p.skip_whitespace();
+            }
+            if p.rest().starts_with("and ") {
+                p.pos += 4;
+                p.skip_whitespace();
+            }
+            if p.rest().is_empty() {
+                p.pos = before;
+                break;
+            }
+        }
+        first = false;
+
+        let (int, frac, frac_digits) = decimal(p)?;
+        p.skip_whitespace();
+        let unit = unit(p)?;
+        let scale = 10u128.pow(frac_digits);
+        let nanos = int
+            .checked_mul(unit.nanos())
+            .and_then(|n| n.checked_add(frac * unit.nanos() / scale))
+            .ok_or(ReadableTimeError::DurationTooLarge)?;
+        total = total
+            .checked_add(nanos)
+            .ok_or(ReadableTimeError::DurationTooLarge)?;
+    }
+    Ok(total)
+}
+
+/// (integer part, fraction digits as a number, number of fraction digits)
+fn decimal(p: &mut Parser) -> Result<(u128, u128, u32), ReadableTimeError> {
+    let digits = |p: &mut Parser| {
+        let len = p.rest().bytes().take_while(u8::is_ascii_digit).count();
+        let s = p.rest()[..len].to_string();
+        p.pos += len;
+        s
+    };
+    let start = p.pos;
+    let int = digits(p);
+    if int.is_empty() {
+        return Err(p.error("number"));
+    }
+    let int = int.parse().map_err(|_| {
+        p.pos = start;
+        p.error("number small enough for a duration")
+    })?;
+    if !p.rest().starts_with('.') {
+        return Ok((int, 0, 0));
+    }
+    p.pos += 1;
+    let frac = digits(p);
+    if frac.is_empty() {
+        return Err(p.error("digits after '.'"));
+    }
+    // digits past nanoseconds can not change the result of any unit
+    let frac = &frac[..frac.len().min(18)];
+    let frac_len = frac.len() as u32;
+    let frac = frac
+        .parse()
+        .map_err(|_| ReadableTimeError::DurationTooLarge)?;
+    Ok((int, frac, frac_len))
+}
+
+fn unit(p: &mut Parser) -> Result<TimeUnit, ReadableTimeError> {
+    let len: usize = p
+        .rest()
+        .chars()
+        .take_while(|c| c.is_alphabetic())
+        .map(char::len_utf8)
+        .sum();
+    let unit = match p.rest()[..len].to_lowercase().as_str() {
+        "ns" | "nsec" | "nsecs" | "nanosecond" | "nanoseconds" => TimeUnit::Nanosecond,
+        "us" | "µs" | "usec" | "usecs" | "microsecond" | "microseconds" => TimeUnit::Microsecond,
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => TimeUnit::Millisecond,
+        "s" | "sec" | "secs" | "second" | "seconds" => TimeUnit::Second,
+        "m" | "min" | "mins" | "minute" | "minutes" => TimeUnit::Minute,
+        "h" | "hr" | "hrs" | "hour" | "hours" => TimeUnit::Hour,
+        "d" | "day" | "days" => TimeUnit::Day,
+        "w" | "wk" | "wks" | "week" | "weeks" => TimeUnit::Week,
+        "y" | "yr" | "yrs" | "year" | "years" => TimeUnit::Year,
+        _ => return Err(p.error("time unit like 'ms', 'h' or 'minutes'")),
+    };
+    p.pos += len;
+    Ok(unit)
+}
+
+/// 'H:MM:SS[.frac]' or 'M:SS[.frac]'
+fn clock(p: &mut Parser) -> Result<u128, ReadableTimeError> {
+    let len = p.rest().bytes().take_while(u8::is_ascii_digit).count();
+    if len == 0 {
+        return Err(p.error("hours or minutes"));
+    }
+    let first: u128 = p.rest()[..len]
+        .parse()
+        .map_err(|_| ReadableTimeError::DurationTooLarge)?;
+    p.pos += len;
+    p.literal(':')?;
+    let second = p.fixed(2, 0..=59, "2 digit minutes or seconds 00-59")? as u128;
+    let (scale, rest) = if p.rest().starts_with(':') {
+        p.pos += 1;
+        let s = p.fixed(2, 0..=59, "2 digit seconds 00-59")? as u128;
+        (3600, second * 60 + s)
+    } else {
+        (60, second)
+    };
+    let mut total = first
+        .checked_mul(scale * 1_000_000_000)
+        .and_then(|n| n.checked_add(rest * 1_000_000_000))
+        .ok_or(ReadableTimeError::DurationTooLarge)?;
+    if p.rest().starts_with('.') {
+        p.pos += 1;
+        let len = p.rest().bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 || len > 9 {
+            return Err(p.error("1-9 fractional second digits"));
+        }
+        let frac: u128 = p.rest()[..len]
+            .parse()
+            .map_err(|_| ReadableTimeError::DurationTooLarge)?;
+        p.pos += len;
+        total += frac * 10u128.pow(9 - len as u32);
+    }
+    Ok(total)
+}
@@ -0,0 +1,122 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! The error type of every fallible function in the crate.
+
+use std::{error::Error, fmt, io};
+
+use crate::ParseError;
+
+/// Every variant is 'Copy' and creating one never allocates.
+/// match on it to handle a failure, or use 'Display' for a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadableTimeError {
+    /// weekday outside 1-7 (1 is sunday)
+    InvalidWeekday(i32),
+    /// month outside 1-12
+    InvalidMonth(i32),
+    /// hour outside 0-23
+    InvalidHour(i32),
+    /// utc offset in seconds that is a day or more
+    InvalidOffset(i32),
+    /// the month has no such day. EXAMPLE: 2025-02-31
+    InvalidDate { year: i64, month: u32, day: u32 },
+    /// day 366 of a non leap year
+    InvalidDayOfYear { year: i64, day: u32 },
+    /// ISO week 0 or 53 of a year with 52 weeks
+    InvalidIsoWeek { year: i64, week: u32 },
+    /// 'localtime_r' returned NULL
+    LocaltimeFailed,
+    /// the system clock is set before 1970
+    ClockBeforeEpoch,
+    /// the result does not fit in 'time_t' or the year does not fit in i32
+    OutOfRange,
+    /// a parsed or added duration is too big for 'Duration' or for i64 seconds
+    DurationTooLarge,
+    /// input did not match the expected layout
+    Parse(ParseError),
+    /// a POSIX TZ string (or TZif footer) did not parse
+    InvalidTzString(ParseError),
+    /// unknown '%' directive in a format string. 'position' is the byte of the '%'
+    UnknownDirective { directive: char, position: usize },
+    /// format string ends with a '%'
+    IncompleteDirective { position: usize },
+    /// time zone names must be relative paths without '.' or '..' parts
+    InvalidTimeZoneName,
+    /// malformed TZif data, with the reason
+    InvalidTzif(&'static str),
+    /// reading a TZif file failed
+    Io(io::ErrorKind),
+}
+
+impl fmt::Display for ReadableTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidWeekday(d) => write!(f, "invalid day of week {d}. only 1-7 are valid"),
+            Self::InvalidMonth(m) => write!(f, "invalid month {m}. month should be 1-12"),
+            Self::InvalidHour(h) => write!(f, "invalid hour {h}. hour should be 0-23"),
+            Self::InvalidOffset(o) => {
+                write!(
+                    f,
+                    "invalid utc offset {o}. offset should be less than a day"
+                )
+            }
+            Self::InvalidDate { year, month, day } => {
+                write!(f, "invalid date. {year}-{month:02} has no day {day}")
+            }
+            Self::InvalidDayOfYear { year, day } => {
+                write!(f, "invalid day of year. {year} has no day {day}")
+            }
+            Self::InvalidIsoWeek { year, week } => {
+                write!(f, "invalid ISO week. {year} has no week {week}")
+            }
+            Self::LocaltimeFailed => {
+                write!(f, "could not get local time. function 'localtime_r' failed")
+            }
+            Self::ClockBeforeEpoch => write!(f, "system clock is set before 1970"),
+            Self::OutOfRange => write!(
+                f,
+                "time out of range. the timestamp does not fit in time_t or the year does not fit in i32"
+            ),
+            Self::DurationTooLarge => write!(f, "duration too large"),
+            Self::Parse(e) => e.fmt(f),
+            Self::InvalidTzString(e) => write!(
+                f,
+                "invalid TZ string. expected {} at byte {}",
+                e.expected, e.position
+            ),
+            Self::UnknownDirective {
+                directive,
+                position,
+            } => write!(
+                f,
+                "unknown format directive '%{directive}' at position {position}"
+            ),
+            Self::IncompleteDirective { position } => {
+                write!(f, "incomplete format directive at position {position}")
+            }
+            Self::InvalidTimeZoneName => write!(f, "invalid time zone name"),
+            Self::InvalidTzif(reason) => write!(f, "invalid TZif data. {reason}"),
+            Self::Io(kind) => write!(f, "could not read time zone: {kind}"),
+        }
+    }
+}
+
+impl Error for ReadableTimeError {}
+
+impl From<ParseError> for ReadableTimeError {
+    fn from(e: ParseError) -> Self {
+        ReadableTimeError::Parse(e)
+    }
+}
+
+impl From<io::Error> for ReadableTimeError {
+    fn from(e: io::Error) -> Self {
+        ReadableTimeError::Io(e.kind())
+    }
+}
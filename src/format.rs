@@ -0,0 +1,222 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! strftime style formatting for 'ReadableTime'.
+
+use crate::{
+    Locale, ReadableTime, ReadableTimeError, civil,
+    names::{MONTHS, WEEKDAYS},
+    offsetf,
+};
+
+pub(crate) const WEEKDAY_NAMES: [&str; 7] = WEEKDAYS[0];
+
+pub(crate) const MONTH_NAMES: [&str; 12] = MONTHS[0];
+
+/// padding modifier written between '%' and the directive
+#[derive(Clone, Copy, PartialEq)]
+enum Pad {
+    Default,
+    /// '%-d'
+    None,
+    /// '%_d'
+    Space,
+    /// '%0e'
+    Zero,
+}
+
+impl ReadableTime {
+    /// Format with strftime style directives.
+    ///
+    /// | directive | meaning                          | example  |
+    /// |-----------|----------------------------------|----------|
+    /// | `%Y`      | year                             | 2025     |
+    /// | `%y`      | year without century             | 25       |
+    /// | `%C`      | century                          | 20       |
+    /// | `%m`      | month 01-12                      | 11       |
+    /// | `%d`      | day 01-31                        | 05       |
+    /// | `%e`      | day, space padded                | ` 5`     |
+    /// | `%H`      | hour 00-23                       | 19       |
+    /// | `%I`      | hour 01-12                       | 07       |
+    /// | `%M`      | minute 00-59                     | 14       |
+    /// | `%S`      | second 00-60                     | 09       |
+    /// | `%N`      | nanosecond 000000000-999999999   | 123456789 |
+    /// | `%3N`     | first 1-9 digits of `%N`, `%3N` is milliseconds | 123 |
+    /// | `%p`      | AM or PM                         | PM       |
+    /// | `%a`      | short weekday name               | Sun      |
+    /// | `%A`      | full weekday name                | Sunday   |
+    /// | `%b` `%h` | short month name                 | Nov      |
+    /// | `%B`      | full month name                  | November |
+    /// | `%u`      | weekday 1-7, monday is 1         | 7        |
+    /// | `%w`      | weekday 0-6, sunday is 0         | 0        |
+    /// | `%j`      | day of year 001-366              | 334      |
+    /// | `%U`      | week of year 00-53, sunday first | 48       |
+    /// | `%W`      | week of year 00-53, monday first | 47       |
+    /// | `%V`      | ISO 8601 week 01-53              | 48       |
+    /// | `%G`      | ISO 8601 week based year         | 2025     |
+    /// | `%g`      | `%G` without century             | 25       |
+    /// | `%z`      | utc offset                       | +0545    |
+    /// | `%:z`     | utc offset with colon            | +05:45   |
+    /// | `%Z`      | time zone abbreviation           | NPT      |
+    /// | `%s`      | seconds since the unix epoch     | 1764464940 |
+    /// | `%F`      | same as `%Y-%m-%d`               |          |
+    /// | `%T`      | same as `%H:%M:%S`               |          |
+    /// | `%R`      | same as `%H:%M`                  |          |
+    /// | `%x`      | date in the layout of the locale | 11/30/2025 |
+    /// | `%X`      | time in the layout of the locale | 07:14:09 AM |
+    /// | `%c`      | date and time of the locale      | Sun Nov 30 07:14:09 2025 |
+    /// | `%n` `%t` | newline and tab                  |          |
+    /// | `%%`      | a literal '%'                    |          |
+    ///
+    /// Numeric directives accept a padding modifier: `%-d` (no padding),
+    /// `%_d` (pad with spaces) and `%0e` (pad with zeros).
+    ///
+    /// Names, '%p' and the '%x' '%X' '%c' layouts are english ('Locale::EN_US'),
+    /// use 'format_localized' for other languages.
+    ///
+    /// EXAMPLE: `rt.format("%A, %-d %B %Y")?` gives "Sunday, 30 November 2025"
+    pub fn format(&self, fmt: &str) -> Result<String, ReadableTimeError> {
+        self.format_localized(fmt, &Locale::EN_US)
+    }
+
+    /// same as 'format' with names, AM/PM text and layouts from 'locale'
+    /// EXAMPLE: `rt.format_localized("%A, %-d. %B %Y", &Locale::DE_DE)?` gives "Sonntag, 30. November 2025"
+    pub fn format_localized(
+        &self,
+        fmt: &str,
+        locale: &Locale,
+    ) -> Result<String, ReadableTimeError> {
+        let mut out = String::with_capacity(fmt.len() + 16);
+        let mut chars = fmt.char_indices();
+
+        while let Some((pos, c)) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+
+            let mut next = chars.next().map(|(_, c)| c);
+            let pad = match next {
+                Some('-') => Pad::None,
+                Some('_') => Pad::Space,
+                Some('0') => Pad::Zero,
+                _ => Pad::Default,
+            };
+            if pad != Pad::Default {
+                next = chars.next().map(|(_, c)| c);
+            }
+            if next == Some(':') {
+                if chars.next().map(|(_, c)| c) != Some('z') {
+                    return Err(ReadableTimeError::UnknownDirective {
+                        directive: ':',
+                        position: pos,
+                    });
+                }
+                out.push_str(&offset_colon(self.utc_offset_seconds));
+                continue;
+            }
+            if let Some(digits @ '1'..='9') = next {
+                if chars.next().map(|(_, c)| c) != Some('N') {
+                    return Err(ReadableTimeError::UnknownDirective {
+                        directive: digits,
+                        position: pos,
+                    });
+                }
+                let frac = format!("{:09}", self.nanosecond);
+                out.push_str(&frac[..digits as usize - '0' as usize]);
+                continue;
+            }
+            let Some(d) = next else {
+                return Err(ReadableTimeError::IncompleteDirective { position: pos });
+            };
+
+            match d {
+                'Y' => num(&mut out, self.year as i64, 1, '0', pad),
+                'y' => num(&mut out, self.year.rem_euclid(100) as i64, 2, '0', pad),
+                'C' => num(&mut out, self.year.div_euclid(100) as i64, 2, '0', pad),
+                'm' => num(&mut out, self.month.number() as i64, 2, '0', pad),
+                'd' => num(&mut out, self.day as i64, 2, '0', pad),
+                'e' => num(&mut out, self.day as i64, 2, ' ', pad),
+                'H' => num(&mut out, self.hour_24 as i64, 2, '0', pad),
+                'I' => num(&mut out, self.hour_12 as i64, 2, '0', pad),
+                'M' => num(&mut out, self.minute as i64, 2, '0', pad),
+                'S' => num(&mut out, self.second as i64, 2, '0', pad),
+                'N' => num(&mut out, self.nanosecond as i64, 9, '0', pad),
+                'j' => num(&mut out, self.day_of_year as i64, 3, '0', pad),
+                'u' => num(&mut out, self.week_day.to_iso() as i64, 1, '0', pad),
+                'w' => num(&mut out, self.week_day.to_c() as i64, 1, '0', pad),
+                'U' => num(&mut out, self.sunday_week() as i64, 2, '0', pad),
+                'W' => num(&mut out, self.monday_week() as i64, 2, '0', pad),
+                'V' => num(&mut out, self.iso_week().1 as i64, 2, '0', pad),
+                'G' => num(&mut out, self.iso_week().0 as i64, 1, '0', pad),
+                'g' => num(
+                    &mut out,
+                    self.iso_week().0.rem_euclid(100) as i64,
+                    2,
+                    '0',
+                    pad,
+                ),
+                's' => num(&mut out, self.unix_timestamp(), 1, '0', pad),
+                'p' => out.push_str(locale.period(self.hour_24)?),
+                'a' => out.push_str(locale.weekday_short(self.week_day)),
+                'b' | 'h' => out.push_str(locale.month_short(self.month)),
+                'A' => out.push_str(locale.weekday(self.week_day)),
+                'B' => out.push_str(locale.month(self.month)),
+                'z' => out.push_str(&offsetf(self.utc_offset_seconds)),
+                'Z' => out.push_str(&self.time_zone),
+                'F' => out.push_str(&self.format_localized("%Y-%m-%d", locale)?),
+                'T' => out.push_str(&self.format_localized("%H:%M:%S", locale)?),
+                'R' => out.push_str(&self.format_localized("%H:%M", locale)?),
+                'x' => out.push_str(&self.format_localized(locale.date_format, locale)?),
+                'X' => out.push_str(&self.format_localized(locale.time_format, locale)?),
+                'c' => out.push_str(&self.format_localized(locale.date_time_format, locale)?),
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                '%' => out.push('%'),
+                _ => {
+                    return Err(ReadableTimeError::UnknownDirective {
+                        directive: d,
+                        position: pos,
+                    });
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// seconds since the unix epoch computed from the fields and 'utc_offset_seconds'
+    pub(crate) fn unix_timestamp(&self) -> i64 {
+        let days = civil::days_from_civil(self.year as i64, self.month.number(), self.day as u32);
+        days * civil::SECONDS_PER_DAY
+            + (self.hour_24 * 3600 + self.minute * 60 + self.second) as i64
+            - self.utc_offset_seconds as i64
+    }
+}
+
+/// '+hh:mm'
+fn offset_colon(offset_seconds: i32) -> String {
+    let s = offsetf(offset_seconds);
+    format!("{}:{}", &s[..3], &s[3..])
+}
+
+fn num(out: &mut String, value: i64, width: usize, default_pad: char, pad: Pad) {
+    let (width, fill) = match pad {
+        Pad::Default => (width, default_pad),
+        Pad::None => (0, ' '),
+        Pad::Space => (width, ' '),
+        Pad::Zero => (width, '0'),
+    };
+    let digits = value.unsigned_abs().to_string();
+    if value < 0 {
+        out.push('-');
+    }
+    for _ in digits.len()..width {
+        out.push(fill);
+    }
+    out.push_str(&digits);
+}
@@ -12,6 +12,8 @@
 //! Basic usage :
 //!
 //! ```
+//! # use readable_time::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let rt:ReadableTime  = get_readable_time()?;
 //! // You  can use rt.month,rt.year,rt.day,rt.hour_24,rt.hour_12,......
 //! // Or specific method to get formatted_time like 'rt.get_timef()' 'rt.get_ptimef',....
@@ -19,19 +21,79 @@
 //! println!("{}",rt.get_timef());             // OUTPUT:  2025-01-01 03:04:05
 //! println!("{}",rt.get_ptimef()?);           // OUTPUT:  Mon Jan 15 2024 03:45 PM
 //! println!("{}",rt.get_extended_ptimef()?);  // OUTPUT:  Sun Nov 30 07:14:00 +0545 2025
+//! println!("{}",rt.get_long_ptimef()?);      // OUTPUT:  Sunday, November 30, 2025 07:14 AM
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To convert a stored timestamp instead of 'now' :
+//! ```
+//! # use readable_time::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let rt = ReadableTime::from_timestamp(1_764_466_440)?;
+//! let rt = ReadableTime::from_system_time(std::time::SystemTime::now())?;
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! If you want to get the time period for 'hour_24' you can use :
 //! ```
+//! # use readable_time::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let hour_24 = 15;
 //! ReadableTime::get_time_period(hour_24)?; // "AM" or "PM"
-//! ````
+//! # Ok(())
+//! # }
+//! ```
+
+mod arith;
+mod calendar;
+pub mod civil;
+mod cmp;
+mod date;
+mod duration;
+mod error;
+mod format;
+mod locale;
+mod month;
+mod names;
+mod parse;
+pub mod posix_tz;
+mod range;
+mod relative;
+mod rfc2822;
+mod rfc3339;
+mod stopwatch;
+pub mod tzif;
+mod week;
+mod weekday;
+mod zone;
+
+pub use arith::MonthOverflow;
+pub use calendar::{Calendar, CalendarStyle};
+pub use date::Date;
+pub use duration::{DurationFormat, DurationStyle, TimeUnit, format_duration, parse_duration};
+pub use error::ReadableTimeError;
+pub use locale::Locale;
+pub use month::Month;
+pub use names::Case;
+pub use parse::ParseError;
+pub use posix_tz::PosixTz;
+pub use range::{DateRange, Step, TimeRange};
+pub use relative::{RelativeOptions, Rounding};
+pub use stopwatch::{StepTimer, Stopwatch, format_elapsed};
+pub use tzif::TimeZone;
+pub use weekday::Weekday;
+pub use zone::Zone;
 
 use std::{
-    error::Error,
     ffi::{CStr, c_char, c_int, c_long},
-    time::{self, UNIX_EPOCH},
+    mem::MaybeUninit,
+    time::{self, SystemTime, UNIX_EPOCH},
 };
 
+use names::PERIODS;
+
 #[allow(nonstandard_style)]
 #[repr(C)]
 struct tm {
@@ -52,30 +114,77 @@ struct tm {
 /// a type to match 'time_t' in c
 pub type time_t = c_long;
 
+/// reentrant variant. the plain 'localtime' shares static storage and is not thread safe
 #[allow(unused)]
 unsafe extern "C" {
-    fn localtime(t: *mut time_t) -> *const tm;
+    fn localtime_r(t: *const time_t, result: *mut tm) -> *mut tm;
 }
 
 #[allow(unused)]
-pub fn time_since_epoch() -> Result<time_t, Box<dyn Error>> {
+pub fn time_since_epoch() -> Result<time_t, ReadableTimeError> {
     Ok(time::SystemTime::now()
-        .duration_since(UNIX_EPOCH)?
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| ReadableTimeError::ClockBeforeEpoch)?
         .as_secs() as time_t)
 }
 
+/// (whole seconds, nanoseconds 0-999_999_999). pre-1970 times are floored so the
+/// nanoseconds always count forward from the seconds
+fn split_system_time(st: SystemTime) -> Result<(time_t, u32), ReadableTimeError> {
+    match st.duration_since(UNIX_EPOCH) {
+        Ok(d) => Ok((
+            time_t::try_from(d.as_secs()).map_err(|_| ReadableTimeError::OutOfRange)?,
+            d.subsec_nanos(),
+        )),
+        Err(e) => {
+            let d = e.duration();
+            let secs = time_t::try_from(d.as_secs()).map_err(|_| ReadableTimeError::OutOfRange)?;
+            if d.subsec_nanos() > 0 {
+                let t = (-secs)
+                    .checked_sub(1)
+                    .ok_or(ReadableTimeError::OutOfRange)?;
+                Ok((t, 1_000_000_000 - d.subsec_nanos()))
+            } else {
+                Ok((-secs, 0))
+            }
+        }
+    }
+}
+
+#[allow(clippy::useless_conversion)] // time_t is only 32 bits on some targets
+fn timestamp_i64(timestamp: time_t) -> i64 {
+    i64::from(timestamp)
+}
+
+/// '+hhmm' for an offset in seconds east of UTC. seconds are dropped
+fn offsetf(offset_seconds: i32) -> String {
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let abs = offset_seconds.unsigned_abs();
+    format!("{}{:02}{:02}", sign, abs / 3600, abs % 3600 / 60)
+}
+
 /// NOTE: for some reason not making fields public makes then inviisible to  lsp ?
 #[derive(Debug, Clone)]
 pub struct ReadableTime {
     pub year: i32,
-    pub month: i32,
+    pub month: Month,
     pub day: i32,
-    pub week_day: i32,
+    pub week_day: Weekday,
     pub hour_24: i32,
     pub hour_12: i32,
     pub minute: i32,
     pub second: i32,
+    /// 0-999_999_999. zero when built from a whole second 'time_t'
+    pub nanosecond: u32,
     pub time_zone: String,
+    /// seconds east of UTC. EXAMPLE: 20700 for +0545
+    pub utc_offset_seconds: i32,
+    /// true if daylight saving time is in effect
+    pub is_dst: bool,
+    /// 1-366. january 1st is 1
+    pub day_of_year: i32,
+    /// the rules this time was converted with. used to stay in the same zone after arithmetic
+    pub zone: Zone,
 }
 
 #[allow(unused)]
@@ -84,132 +193,319 @@ impl ReadableTime {
     /// 'Y-M-D H-m-S'
     /// EXAMPLE: 2025-01-01 03:04:05
     pub fn get_timef(&self) -> String {
-        format!(
-            "{}-{:02}-{:02} {:02}:{:02}:{:02}",
-            self.year, self.month, self.day, self.hour_24, self.minute, self.second
-        )
+        self.format("%Y-%m-%d %H:%M:%S")
+            .expect("numeric directives can not fail")
+    }
+
+    /// 'get_timef' with milliseconds
+    /// EXAMPLE: 2025-01-01 03:04:05.123
+    pub fn get_timef_ms(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S.%3N")
+            .expect("numeric directives can not fail")
+    }
+
+    /// 'get_timef' with microseconds
+    /// EXAMPLE: 2025-01-01 03:04:05.123456
+    pub fn get_timef_us(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S.%6N")
+            .expect("numeric directives can not fail")
+    }
+
+    /// 'get_timef' with nanoseconds
+    /// EXAMPLE: 2025-01-01 03:04:05.123456789
+    pub fn get_timef_ns(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S.%N")
+            .expect("numeric directives can not fail")
     }
 
     /// Get prettier date string
     /// EXAMPLE: Mon Jan 15 2024 03:45 PM
-    pub fn get_ptimef(&self) -> Result<String, Box<dyn Error>> {
-        Ok(format!(
-            "{} {} {} {} {:02}:{:02} {}",
-            Self::weekstr(self.week_day)?,
-            Self::monthstr(self.month)?,
-            self.day,
-            self.year,
-            self.hour_12,
-            self.minute,
-            Self::get_time_period(self.hour_24)?,
-        ))
+    pub fn get_ptimef(&self) -> Result<String, ReadableTimeError> {
+        self.format("%a %b %-d %Y %I:%M %p")
     }
 
     /// Get pretty formatted date with extra info
     /// EXAMPLE: Sun Nov 30 07:14:00 +0545 2025
-    pub fn get_extended_ptimef(&self) -> Result<String, Box<dyn Error>> {
+    pub fn get_extended_ptimef(&self) -> Result<String, ReadableTimeError> {
+        self.format("%a %b %-d %H:%M:%S %z %Y")
+    }
+
+    /// Long form date with full names
+    /// EXAMPLE: Sunday, November 30, 2025
+    pub fn get_long_datef(&self) -> String {
+        self.format_localized(Locale::EN_US.long_date_format, &Locale::EN_US)
+            .expect("the en_US layout only uses name and numeric directives")
+    }
+
+    /// Long form date and 12 hour time with full names
+    /// EXAMPLE: Sunday, November 30, 2025 07:14 AM
+    pub fn get_long_ptimef(&self) -> Result<String, ReadableTimeError> {
+        self.get_long_ptimef_localized(&Locale::EN_US)
+    }
+
+    /// 'get_ptimef' with names and AM/PM text from 'locale'
+    /// EXAMPLE: So Nov 30 2025 07:14 AM
+    pub fn get_ptimef_localized(&self, locale: &Locale) -> Result<String, ReadableTimeError> {
+        self.format_localized("%a %b %-d %Y %I:%M %p", locale)
+    }
+
+    /// 'get_extended_ptimef' with names from 'locale'
+    /// EXAMPLE: So Nov 30 07:14:00 +0545 2025
+    pub fn get_extended_ptimef_localized(
+        &self,
+        locale: &Locale,
+    ) -> Result<String, ReadableTimeError> {
+        self.format_localized("%a %b %-d %H:%M:%S %z %Y", locale)
+    }
+
+    /// long form date in the layout of 'locale'.
+    /// fails like 'format_localized' if a registered locale has a bad 'long_date_format'
+    /// EXAMPLE: Sonntag, 30. November 2025
+    pub fn get_long_datef_localized(&self, locale: &Locale) -> Result<String, ReadableTimeError> {
+        self.format_localized(locale.long_date_format, locale)
+    }
+
+    /// long form date of 'locale' followed by the 12 hour time
+    /// EXAMPLE: Sonntag, 30. November 2025 07:14 AM
+    pub fn get_long_ptimef_localized(&self, locale: &Locale) -> Result<String, ReadableTimeError> {
         Ok(format!(
-            "{} {} {} {:02}:{:02}:{:02} {} {}",
-            Self::weekstr(self.week_day)?,
-            Self::monthstr(self.month)?,
-            self.day,
-            self.hour_24,
-            self.minute,
-            self.second,
-            self.time_zone,
-            self.year
+            "{} {}",
+            self.get_long_datef_localized(locale)?,
+            self.format_localized("%I:%M %p", locale)?
         ))
     }
-    pub fn weekstr(weekday: i32) -> Result<String, Box<dyn Error>> {
-        match weekday {
-            1 => Ok("Sun".to_string()),
-            2 => Ok("Mon".to_string()),
-            3 => Ok("Tue".to_string()),
-            4 => Ok("Wed".to_string()),
-            5 => Ok("Thu".to_string()),
-            6 => Ok("Fri".to_string()),
-            7 => Ok("Sat".to_string()),
-            _ => Err("invalid day of week.only 1-7 are valid days".into()),
-        }
+
+    /// utc offset as '+hhmm'
+    /// EXAMPLE: +0545
+    pub fn get_offsetf(&self) -> String {
+        offsetf(self.utc_offset_seconds)
     }
 
-    pub fn monthstr(month: i32) -> Result<String, Box<dyn Error>> {
-        match month {
-            1 => Ok("Jan".to_string()),
-            2 => Ok("Feb".to_string()),
-            3 => Ok("Mar".to_string()),
-            4 => Ok("Apr".to_string()),
-            5 => Ok("May".to_string()),
-            6 => Ok("Jun".to_string()),
-            7 => Ok("Jul".to_string()),
-            8 => Ok("Aug".to_string()),
-            9 => Ok("Sep".to_string()),
-            10 => Ok("Oct".to_string()),
-            11 => Ok("Nov".to_string()),
-            12 => Ok("Dec".to_string()),
-            _ => Err("invalid month. month should be 1-12".into()),
+    /// Convert any unix timestamp (negative values are before 1970) to local time
+    pub fn from_timestamp(timestamp: time_t) -> Result<ReadableTime, ReadableTimeError> {
+        // 'localtime_r' only says that it failed. a year outside i32 is the usual reason
+        let days = timestamp_i64(timestamp).div_euclid(civil::SECONDS_PER_DAY);
+        if i32::try_from(civil::civil_from_days(days).0).is_err() {
+            return Err(ReadableTimeError::OutOfRange);
         }
-    }
-    pub fn get_time_period(hour_24: i32) -> Result<String, Box<dyn Error>> {
-        match hour_24 {
-            12..=23 => Ok("PM".to_string()),
-            0..=11 => Ok("AM".to_string()),
-            _ => Err("invalid hour. hour should be in 0-23 format.".into()),
+        let mut lt = MaybeUninit::<tm>::uninit();
+        unsafe {
+            if localtime_r(&timestamp, lt.as_mut_ptr()).is_null() {
+                return Err(ReadableTimeError::LocaltimeFailed);
+            }
+            Self::from_tm(lt.assume_init_ref())
         }
     }
-}
 
-pub fn get_readable_time() -> Result<ReadableTime, Box<dyn Error>> {
-    let mut t = time_since_epoch()?;
-    let lt;
-
-    let year: i32;
-    let month: i32;
-    let day: i32;
-    let week_day: i32;
-    let hour_24: i32;
-    let hour_12: i32;
-    let minute: i32;
-    let second: i32;
-    let time_zone;
-
-    unsafe {
-        lt = localtime(&mut t);
-        if lt.is_null() {
-            return Err("Could not get local time.function 'localtime' failed.".into());
+    /// Convert any unix timestamp to UTC. result does not depend on the 'TZ' of the host
+    pub fn utc_from_timestamp(timestamp: time_t) -> Result<ReadableTime, ReadableTimeError> {
+        Self::from_offset(timestamp, 0, false, "UTC".to_string(), Zone::Utc)
+    }
+
+    /// same instant in UTC
+    pub fn to_utc(&self) -> Result<ReadableTime, ReadableTimeError> {
+        self.in_zone(&Zone::Utc)
+    }
+
+    /// Convert any unix timestamp to a fixed offset from UTC (east is positive).
+    /// 'time_zone' is set to the offset itself, like '+0545'
+    pub fn from_timestamp_with_offset(
+        timestamp: time_t,
+        offset_seconds: i32,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        if offset_seconds.unsigned_abs() >= 86_400 {
+            return Err(ReadableTimeError::InvalidOffset(offset_seconds));
         }
+        Self::from_offset(
+            timestamp,
+            offset_seconds,
+            false,
+            offsetf(offset_seconds),
+            Zone::Fixed(offset_seconds),
+        )
+    }
+
+    /// Convert any unix timestamp to the local time of 'tz'.
+    /// 'time_zone' is set to the abbreviation used by the zone at that instant
+    pub fn from_timestamp_in(
+        timestamp: time_t,
+        tz: &TimeZone,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        let lt = tz.local_time_type(timestamp_i64(timestamp));
+        Self::from_offset(
+            timestamp,
+            lt.utc_offset,
+            lt.is_dst,
+            lt.abbreviation,
+            Zone::Tz(tz.clone()),
+        )
+    }
+
+    /// pure rust conversion using the 'civil' module
+    fn from_offset(
+        timestamp: time_t,
+        offset_seconds: i32,
+        is_dst: bool,
+        time_zone: String,
+        zone: Zone,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        let local = timestamp_i64(timestamp)
+            .checked_add(offset_seconds as i64)
+            .ok_or(ReadableTimeError::OutOfRange)?;
+        let days = local.div_euclid(civil::SECONDS_PER_DAY);
+        let secs = local.rem_euclid(civil::SECONDS_PER_DAY) as i32;
+        let (year, month, day) = civil::civil_from_days(days);
+        let year = i32::try_from(year).map_err(|_| ReadableTimeError::OutOfRange)?;
+        let hour_24 = secs / 3600;
+
+        Ok(ReadableTime {
+            year,
+            month: Month::from_number(month).expect("civil_from_days gives 1-12"),
+            day: day as i32,
+            week_day: Weekday::from_c(civil::weekday_from_days(days))
+                .expect("weekday_from_days gives 0-6"),
+            hour_24,
+            hour_12: Self::hour_12(hour_24),
+            minute: secs % 3600 / 60,
+            second: secs % 60,
+            nanosecond: 0,
+            time_zone,
+            utc_offset_seconds: offset_seconds,
+            is_dst,
+            day_of_year: civil::day_of_year(year as i64, month, day) as i32,
+            zone,
+        })
+    }
 
-        year = (*lt).tm_year + 1900;
-        month = (*lt).tm_mon + 1;
-        day = (*lt).tm_mday;
-        week_day = (*lt).tm_wday + 1;
-        hour_24 = (*lt).tm_hour;
-        hour_12 = match hour_24 {
+    fn hour_12(hour_24: i32) -> i32 {
+        match hour_24 {
             0 => 12,
             1..=12 => hour_24,
             13..=23 => hour_24 - 12,
             _ => hour_24,
-        };
+        }
+    }
+
+    /// build from the broken down time filled by libc
+    unsafe fn from_tm(lt: &tm) -> Result<ReadableTime, ReadableTimeError> {
+        let year = lt
+            .tm_year
+            .checked_add(1900)
+            .ok_or(ReadableTimeError::OutOfRange)?;
+        let hour_24 = lt.tm_hour;
+        let hour_12 = Self::hour_12(hour_24);
 
-        minute = (*lt).tm_min;
-        second = (*lt).tm_sec;
-        let tz = (*lt).tm_zone;
-        time_zone = if !tz.is_null() {
-            CStr::from_ptr(tz).to_string_lossy().to_string()
+        let time_zone = if !lt.tm_zone.is_null() {
+            unsafe { CStr::from_ptr(lt.tm_zone).to_string_lossy().to_string() }
         } else {
             "unknown time_zone".to_string()
+        };
+
+        Ok(ReadableTime {
+            year,
+            month: Month::from_c(lt.tm_mon as u32).ok_or(ReadableTimeError::LocaltimeFailed)?,
+            day: lt.tm_mday,
+            week_day: Weekday::from_c(lt.tm_wday as u32)
+                .ok_or(ReadableTimeError::LocaltimeFailed)?,
+            hour_24,
+            hour_12,
+            minute: lt.tm_min,
+            second: lt.tm_sec,
+            nanosecond: 0,
+            time_zone,
+            utc_offset_seconds: lt.tm_gmtoff as i32,
+            is_dst: lt.tm_isdst > 0,
+            day_of_year: lt.tm_yday + 1,
+            zone: Zone::Local,
+        })
+    }
+
+    /// Convert a 'SystemTime' to local time, keeping the nanoseconds
+    pub fn from_system_time(st: SystemTime) -> Result<ReadableTime, ReadableTimeError> {
+        Self::from_system_time_in(st, &Zone::Local)
+    }
+
+    /// Convert a 'SystemTime' to 'zone', keeping the nanoseconds
+    pub fn from_system_time_in(
+        st: SystemTime,
+        zone: &Zone,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        let (t, nanosecond) = split_system_time(st)?;
+        let mut rt = zone.at(t)?;
+        rt.nanosecond = nanosecond;
+        Ok(rt)
+    }
+
+    /// 0-999
+    pub fn millisecond(&self) -> u32 {
+        self.nanosecond / 1_000_000
+    }
+
+    /// 0-999_999
+    pub fn microsecond(&self) -> u32 {
+        self.nanosecond / 1_000
+    }
+
+    /// "Sun" for 1 .. "Sat" for 7. same as 'Weekday::short_name'
+    pub fn weekstr(weekday: i32) -> Result<&'static str, ReadableTimeError> {
+        Self::weekday_from_i32(weekday).map(Weekday::short_name)
+    }
+
+    /// "Sunday" for 1 .. "Saturday" for 7. same as 'Weekday::long_name'
+    pub fn weekstr_long(weekday: i32) -> Result<&'static str, ReadableTimeError> {
+        Self::weekday_from_i32(weekday).map(Weekday::long_name)
+    }
+
+    fn weekday_from_i32(weekday: i32) -> Result<Weekday, ReadableTimeError> {
+        weekday
+            .checked_sub(1)
+            .and_then(|w| u32::try_from(w).ok())
+            .and_then(Weekday::from_c)
+            .ok_or(ReadableTimeError::InvalidWeekday(weekday))
+    }
+
+    /// "Jan" for 1 .. "Dec" for 12. same as 'Month::short_name'
+    pub fn monthstr(month: i32) -> Result<&'static str, ReadableTimeError> {
+        Self::month_from_i32(month).map(Month::short_name)
+    }
+
+    /// "January" for 1 .. "December" for 12. same as 'Month::long_name'
+    pub fn monthstr_long(month: i32) -> Result<&'static str, ReadableTimeError> {
+        Self::month_from_i32(month).map(Month::long_name)
+    }
+
+    fn month_from_i32(month: i32) -> Result<Month, ReadableTimeError> {
+        u32::try_from(month)
+            .ok()
+            .and_then(Month::from_number)
+            .ok_or(ReadableTimeError::InvalidMonth(month))
+    }
+
+    /// "AM" for 0-11, "PM" for 12-23
+    pub fn get_time_period(hour_24: i32) -> Result<&'static str, ReadableTimeError> {
+        Self::get_time_period_in(hour_24, Case::Upper)
+    }
+
+    /// "AM"/"PM" or "am"/"pm". 'Case::Title' is the same as 'Case::Upper'
+    pub fn get_time_period_in(hour_24: i32, case: Case) -> Result<&'static str, ReadableTimeError> {
+        match hour_24 {
+            0..=11 => Ok(PERIODS[case as usize][0]),
+            12..=23 => Ok(PERIODS[case as usize][1]),
+            _ => Err(ReadableTimeError::InvalidHour(hour_24)),
         }
-    };
-
-    Ok(ReadableTime {
-        year,
-        month,
-        day,
-        week_day,
-        hour_24,
-        hour_12,
-        minute,
-        second,
-        time_zone,
-    })
+    }
+}
+
+pub fn get_readable_time() -> Result<ReadableTime, ReadableTimeError> {
+    ReadableTime::from_system_time(SystemTime::now())
+}
+
+/// same as 'get_readable_time' but in UTC
+pub fn get_readable_time_utc() -> Result<ReadableTime, ReadableTimeError> {
+    ReadableTime::from_system_time_in(SystemTime::now(), &Zone::Utc)
+}
+
+/// same as 'get_readable_time' but in the given zone
+pub fn get_readable_time_in(tz: &TimeZone) -> Result<ReadableTime, ReadableTimeError> {
+    ReadableTime::from_system_time_in(SystemTime::now(), &Zone::Tz(tz.clone()))
 }
@@ -0,0 +1,330 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Localized names, AM/PM text and default layouts for the formatters.
+//!
+//! built in: English (en_US), Nepali (ne_NP), German (de_DE), French (fr_FR),
+//! Spanish (es_ES) and Japanese (ja_JP). more can be added with 'Locale::register'.
+
+use std::sync::RwLock;
+
+use crate::{
+    Month, ReadableTimeError, Weekday,
+    names::{MONTHS, PERIODS, WEEKDAYS},
+};
+
+/// A table of names and layouts. every field is a `&'static str` so a 'Locale' is 'Copy'
+/// and formatting with it never allocates for the lookup.
+/// build your own with struct syntax, usually starting from a built in one:
+///
+/// ```
+/// # use readable_time::*;
+/// let pirate = Locale {
+///     name: "en_PIRATE",
+///     am_pm: ["morn", "eve"],
+///     ..Locale::EN_US
+/// };
+/// Locale::register(pirate);
+/// assert_eq!(Locale::get("en_PIRATE").unwrap().am_pm[1], "eve");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// POSIX style name like "de_DE"
+    pub name: &'static str,
+    /// sunday first, used by '%A'
+    pub weekdays: [&'static str; 7],
+    /// sunday first, used by '%a'
+    pub weekdays_short: [&'static str; 7],
+    /// january first, used by '%B'
+    pub months: [&'static str; 12],
+    /// january first, used by '%b'
+    pub months_short: [&'static str; 12],
+    /// used by '%p'
+    pub am_pm: [&'static str; 2],
+    /// layout of '%x'
+    pub date_format: &'static str,
+    /// layout of '%X'
+    pub time_format: &'static str,
+    /// layout of '%c'
+    pub date_time_format: &'static str,
+    /// layout of 'get_long_datef_localized'
+    pub long_date_format: &'static str,
+}
+
+/// locales added with 'Locale::register'
+static REGISTERED: RwLock<Vec<Locale>> = RwLock::new(Vec::new());
+
+impl Locale {
+    pub const EN_US: Locale = Locale {
+        name: "en_US",
+        weekdays: WEEKDAYS[0],
+        weekdays_short: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        months: MONTHS[0],
+        months_short: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        am_pm: PERIODS[0],
+        date_format: "%m/%d/%Y",
+        time_format: "%I:%M:%S %p",
+        date_time_format: "%a %b %e %H:%M:%S %Y",
+        long_date_format: "%A, %B %-d, %Y",
+    };
+
+    pub const NE_NP: Locale = Locale {
+        name: "ne_NP",
+        weekdays: [
+            "आइतबार",
+            "सोमबार",
+            "मङ्गलबार",
+            "बुधबार",
+            "बिहिबार",
+            "शुक्रबार",
+            "शनिबार",
+        ],
+        weekdays_short: ["आइत", "सोम", "मङ्गल", "बुध", "बिहि", "शुक्र", "शनि"],
+        months: [
+            "जनवरी",
+            "फेब्रुअरी",
+            "मार्च",
+            "अप्रिल",
+            "मे",
+            "जुन",
+            "जुलाई",
+            "अगस्ट",
+            "सेप्टेम्बर",
+            "अक्टोबर",
+            "नोभेम्बर",
+            "डिसेम्बर",
+        ],
+        months_short: [
+            "जन",
+            "फेब",
+            "मार्च",
+            "अप्रि",
+            "मे",
+            "जुन",
+            "जुला",
+            "अग",
+            "सेप",
+            "अक्टो",
+            "नोभे",
+            "डिसे",
+        ],
+        am_pm: ["पूर्वाह्न", "अपराह्न"],
+        date_format: "%Y/%m/%d",
+        time_format: "%H:%M:%S",
+        date_time_format: "%A %Y %B %-d %H:%M:%S",
+        long_date_format: "%Y %B %-d, %A",
+    };
+
+    pub const DE_DE: Locale = Locale {
+        name: "de_DE",
+        weekdays: [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        weekdays_short: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        months_short: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        am_pm: ["AM", "PM"],
+        date_format: "%d.%m.%Y",
+        time_format: "%H:%M:%S",
+        date_time_format: "%a %d %b %Y %H:%M:%S",
+        long_date_format: "%A, %-d. %B %Y",
+    };
+
+    pub const FR_FR: Locale = Locale {
+        name: "fr_FR",
+        weekdays: [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+        weekdays_short: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        months_short: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        am_pm: ["AM", "PM"],
+        date_format: "%d/%m/%Y",
+        time_format: "%H:%M:%S",
+        date_time_format: "%a %-d %b %Y %H:%M:%S",
+        long_date_format: "%A %-d %B %Y",
+    };
+
+    pub const ES_ES: Locale = Locale {
+        name: "es_ES",
+        weekdays: [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+        weekdays_short: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        months_short: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+        ],
+        am_pm: ["a. m.", "p. m."],
+        date_format: "%d/%m/%Y",
+        time_format: "%H:%M:%S",
+        date_time_format: "%a %-d %b %Y %H:%M:%S",
+        long_date_format: "%A, %-d de %B de %Y",
+    };
+
+    pub const JA_JP: Locale = Locale {
+        name: "ja_JP",
+        weekdays: [
+            "日曜日",
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+        ],
+        weekdays_short: ["日", "月", "火", "水", "木", "金", "土"],
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        months_short: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        am_pm: ["午前", "午後"],
+        date_format: "%Y/%m/%d",
+        time_format: "%H:%M:%S",
+        date_time_format: "%Y年%m月%d日 %H時%M分%S秒",
+        long_date_format: "%Y年%-m月%-d日 %A",
+    };
+
+    pub const BUILT_IN: [Locale; 6] = [
+        Locale::EN_US,
+        Locale::NE_NP,
+        Locale::DE_DE,
+        Locale::FR_FR,
+        Locale::ES_ES,
+        Locale::JA_JP,
+    ];
+
+    /// Find a locale by name. registered locales are searched before the built in ones.
+    /// accepts the forms found in 'LANG': "de_DE", "de-DE", "de_DE.UTF-8", or only the
+    /// language "de" which picks the first locale of that language
+    pub fn get(name: &str) -> Option<Locale> {
+        let name = name.split(['.', '@']).next().unwrap_or(name);
+        let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+        let all = || registered.iter().rev().chain(Self::BUILT_IN.iter());
+        all()
+            .find(|l| same_name(l.name, name))
+            .or_else(|| all().find(|l| same_name(l.language(), name)))
+            .copied()
+    }
+
+    /// Add a locale or replace a registered one with the same name.
+    /// built in locales can be overridden this way too, 'get' finds the registered one first
+    pub fn register(locale: Locale) {
+        let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+        registered.retain(|l| l.name != locale.name);
+        registered.push(locale);
+    }
+
+    /// "de" for "de_DE"
+    pub fn language(&self) -> &'static str {
+        self.name.split(['_', '-']).next().unwrap_or(self.name)
+    }
+
+    pub fn weekday(&self, day: Weekday) -> &'static str {
+        self.weekdays[day.to_c() as usize]
+    }
+
+    pub fn weekday_short(&self, day: Weekday) -> &'static str {
+        self.weekdays_short[day.to_c() as usize]
+    }
+
+    pub fn month(&self, month: Month) -> &'static str {
+        self.months[month.to_c() as usize]
+    }
+
+    pub fn month_short(&self, month: Month) -> &'static str {
+        self.months_short[month.to_c() as usize]
+    }
+
+    /// AM text for 0-11, PM text for 12-23
+    pub fn period(&self, hour_24: i32) -> Result<&'static str, ReadableTimeError> {
+        match hour_24 {
+            0..=11 => Ok(self.am_pm[0]),
+            12..=23 => Ok(self.am_pm[1]),
+            _ => Err(ReadableTimeError::InvalidHour(hour_24)),
+        }
+    }
+}
+
+/// ascii case insensitive, '-' and '_' are the same
+fn same_name(a: &str, b: &str) -> bool {
+    let norm = |c: u8| {
+        if c == b'-' {
+            b'_'
+        } else {
+            c.to_ascii_lowercase()
+        }
+    };
+    a.len() == b.len() && a.bytes().zip(b.bytes()).all(|(x, y)| norm(x) == norm(y))
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN_US
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Month of the year.
+
+use std::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{Case, ParseError, ReadableTimeError, civil, names::MONTHS};
+
+/// A month of the year. 'as u32' gives 1-12
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Month {
+    January = 1,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// next month. december is followed by january
+    pub fn succ(self) -> Month {
+        self + 1
+    }
+
+    /// previous month. january is preceded by december
+    pub fn pred(self) -> Month {
+        self - 1
+    }
+
+    /// 1 = january .. 12 = december, like ISO 8601 and '%m'
+    pub fn number(self) -> u32 {
+        self as u32
+    }
+
+    /// 1 = january .. 12 = december
+    pub fn from_number(n: u32) -> Option<Month> {
+        match n {
+            1..=12 => Some(Self::ALL[n as usize - 1]),
+            _ => None,
+        }
+    }
+
+    /// C numbering (0 = january .. 11 = december) like 'tm_mon'
+    pub fn to_c(self) -> u32 {
+        self as u32 - 1
+    }
+
+    /// 0 = january .. 11 = december
+    pub fn from_c(n: u32) -> Option<Month> {
+        Self::ALL.get(n as usize).copied()
+    }
+
+    /// number of days in the month of 'year'
+    pub fn days(self, year: i64) -> u32 {
+        civil::days_in_month(year, self as u32)
+    }
+
+    /// "Jan"
+    pub fn short_name(self) -> &'static str {
+        self.short_name_in(Case::Title)
+    }
+
+    /// "January"
+    pub fn long_name(self) -> &'static str {
+        self.long_name_in(Case::Title)
+    }
+
+    /// "Jan", "JAN" or "jan"
+    pub fn short_name_in(self, case: Case) -> &'static str {
+        &self.long_name_in(case)[..3]
+    }
+
+    /// "January", "JANUARY" or "january"
+    pub fn long_name_in(self, case: Case) -> &'static str {
+        MONTHS[case as usize][self as usize - 1]
+    }
+}
+
+/// 'n' months later, wrapping around the year
+impl Add<i64> for Month {
+    type Output = Month;
+
+    fn add(self, months: i64) -> Month {
+        Self::ALL[(self as i64 - 1 + months.rem_euclid(12)).rem_euclid(12) as usize]
+    }
+}
+
+impl Sub<i64> for Month {
+    type Output = Month;
+
+    fn sub(self, months: i64) -> Month {
+        Self::ALL[(self as i64 - 1 - months.rem_euclid(12)).rem_euclid(12) as usize]
+    }
+}
+
+/// short or long english name, case insensitive. "jan", "January"
+impl FromStr for Month {
+    type Err = ReadableTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|m| {
+                s.eq_ignore_ascii_case(m.short_name()) || s.eq_ignore_ascii_case(m.long_name())
+            })
+            .ok_or(ReadableTimeError::Parse(ParseError {
+                position: 0,
+                expected: "month name",
+            }))
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! English weekday and month names in every letter case, as static tables
+//! so no name lookup allocates.
+
+/// letter case of a name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Case {
+    /// "Sunday"
+    #[default]
+    Title,
+    /// "SUNDAY"
+    Upper,
+    /// "sunday"
+    Lower,
+}
+
+/// indexed by 'Case' then by 'Weekday as usize'
+pub(crate) const WEEKDAYS: [[&str; 7]; 3] = [
+    [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+    [
+        "SUNDAY",
+        "MONDAY",
+        "TUESDAY",
+        "WEDNESDAY",
+        "THURSDAY",
+        "FRIDAY",
+        "SATURDAY",
+    ],
+    [
+        "sunday",
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+    ],
+];
+
+/// indexed by 'Case' then by 'Month as usize - 1'
+pub(crate) const MONTHS: [[&str; 12]; 3] = [
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    [
+        "JANUARY",
+        "FEBRUARY",
+        "MARCH",
+        "APRIL",
+        "MAY",
+        "JUNE",
+        "JULY",
+        "AUGUST",
+        "SEPTEMBER",
+        "OCTOBER",
+        "NOVEMBER",
+        "DECEMBER",
+    ],
+    [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ],
+];
+
+/// indexed by 'Case' then by AM = 0, PM = 1
+pub(crate) const PERIODS: [[&str; 2]; 3] = [["AM", "PM"], ["AM", "PM"], ["am", "pm"]];
@@ -0,0 +1,486 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! strptime style parsing. the inverse of 'ReadableTime::format'.
+
+use std::{error::Error, fmt};
+
+use crate::{
+    ReadableTime, ReadableTimeError, Zone, civil,
+    format::{MONTH_NAMES, WEEKDAY_NAMES},
+    offsetf, time_t,
+};
+
+/// returned when the input does not match the format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    /// byte offset into the input
+    pub position: usize,
+    /// what was expected at 'position'. EXAMPLE: "month 01-12", "':'"
+    pub expected: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not parse time. expected {} at byte {}",
+            self.expected, self.position
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// fields collected while parsing. missing date parts default to 1970-01-01
+#[derive(Default)]
+pub(crate) struct Parsed {
+    pub(crate) year: Option<i64>,
+    /// '%C' and '%y' are combined at the end
+    pub(crate) century: Option<i64>,
+    pub(crate) year_2digit: Option<i64>,
+    pub(crate) month: Option<u32>,
+    pub(crate) day: Option<u32>,
+    pub(crate) day_of_year: Option<u32>,
+    pub(crate) hour_24: Option<i32>,
+    pub(crate) hour_12: Option<i32>,
+    pub(crate) pm: Option<bool>,
+    pub(crate) minute: i32,
+    pub(crate) second: i32,
+    pub(crate) nanosecond: u32,
+    /// (weekday 0 = Sunday, position)
+    pub(crate) week_day: Option<(u32, usize)>,
+    pub(crate) offset: Option<i32>,
+    pub(crate) time_zone: Option<String>,
+    pub(crate) timestamp: Option<i64>,
+}
+
+/// cursor over the input. shared with the RFC parsers
+pub(crate) struct Parser<'a> {
+    pub(crate) input: &'a str,
+    pub(crate) pos: usize,
+}
+
+impl ReadableTime {
+    /// Parse 'input' using the same directives as 'ReadableTime::format'.
+    ///
+    /// whitespace in the format matches any amount of whitespace (including none) in the input.
+    /// names ('%a', '%b', '%p', ...) are case insensitive and accept both short and full forms.
+    /// '%Y' reads at most 4 digits so basic layouts like '%Y%m%d' work.
+    /// '%N' reads a fraction of any length and '%3N' exactly 3 digits. a fraction like
+    /// '.250' right after '%S' is accepted even when the format does not mention it.
+    ///
+    /// Without '%z', '%Z' or '%s' the fields are taken as UTC.
+    /// '%Z' only knows the offset of "UTC" and the RFC 2822 names ("GMT", "EST", "PDT", ...),
+    /// other names are kept in 'time_zone' with a zero offset.
+    pub fn parse(input: &str, format: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let mut parsed = Parsed::default();
+        p.run(format, &mut parsed)?;
+        if p.pos != input.len() {
+            return Err(p.error("end of input"));
+        }
+        parsed.build()
+    }
+
+    /// Parse the layout produced by 'get_timef'
+    /// EXAMPLE: 2025-01-01 03:04:05
+    pub fn parse_timef(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        Self::parse(input, "%Y-%m-%d %H:%M:%S")
+    }
+}
+
+impl Parser<'_> {
+    pub(crate) fn error(&self, expected: &'static str) -> ReadableTimeError {
+        ReadableTimeError::Parse(ParseError {
+            position: self.pos,
+            expected,
+        })
+    }
+
+    pub(crate) fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    pub(crate) fn literal(&mut self, c: char) -> Result<(), ReadableTimeError> {
+        if !self.rest().starts_with(c) {
+            return Err(self.error(quoted(c)));
+        }
+        self.pos += c.len_utf8();
+        Ok(())
+    }
+
+    /// up to 'max_digits' digits. at least one is required
+    pub(crate) fn number(
+        &mut self,
+        max_digits: usize,
+        what: &'static str,
+    ) -> Result<i64, ReadableTimeError> {
+        let len = self
+            .rest()
+            .bytes()
+            .take(max_digits)
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if len == 0 {
+            return Err(self.error(what));
+        }
+        let n = self.rest()[..len].parse().map_err(|_| self.error(what))?;
+        self.pos += len;
+        Ok(n)
+    }
+
+    /// fraction digits after the separator as nanoseconds. digits past the 9th are
+    /// checked but dropped
+    pub(crate) fn nanoseconds(&mut self) -> Result<u32, ReadableTimeError> {
+        let len = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return Err(self.error("fractional second digits"));
+        }
+        let nanos = self.rest().bytes().take(9).take_while(u8::is_ascii_digit);
+        let mut n = nanos.fold(0, |n, b| n * 10 + (b - b'0') as u32);
+        for _ in len..9 {
+            n *= 10;
+        }
+        self.pos += len;
+        Ok(n)
+    }
+
+    /// exactly 'digits' digits inside 'range'
+    pub(crate) fn fixed(
+        &mut self,
+        digits: usize,
+        range: std::ops::RangeInclusive<i64>,
+        what: &'static str,
+    ) -> Result<i64, ReadableTimeError> {
+        let start = self.pos;
+        let n = self.ranged(digits, range, what)?;
+        if self.pos - start != digits {
+            self.pos = start;
+            return Err(self.error(what));
+        }
+        Ok(n)
+    }
+
+    /// number that must be inside 'range'. the error points at the start of the number
+    pub(crate) fn ranged(
+        &mut self,
+        max_digits: usize,
+        range: std::ops::RangeInclusive<i64>,
+        what: &'static str,
+    ) -> Result<i64, ReadableTimeError> {
+        let start = self.pos;
+        let n = self.number(max_digits, what)?;
+        if !range.contains(&n) {
+            self.pos = start;
+            return Err(self.error(what));
+        }
+        Ok(n)
+    }
+
+    pub(crate) fn signed(
+        &mut self,
+        max_digits: usize,
+        what: &'static str,
+    ) -> Result<i64, ReadableTimeError> {
+        let negative = match self.rest().as_bytes().first() {
+            Some(b'-') => {
+                self.pos += 1;
+                true
+            }
+            Some(b'+') => {
+                self.pos += 1;
+                false
+            }
+            _ => false,
+        };
+        let n = self.number(max_digits, what)?;
+        Ok(if negative { -n } else { n })
+    }
+
+    /// index of the matching name. full names are tried before the 3 letter form
+    pub(crate) fn name(
+        &mut self,
+        names: &[&str],
+        what: &'static str,
+    ) -> Result<usize, ReadableTimeError> {
+        let rest = self.rest();
+        for (i, name) in names.iter().enumerate() {
+            for candidate in [*name, name.get(..3).unwrap_or(name)] {
+                if rest.len() >= candidate.len()
+                    && rest.is_char_boundary(candidate.len())
+                    && rest[..candidate.len()].eq_ignore_ascii_case(candidate)
+                {
+                    self.pos += candidate.len();
+                    return Ok(i);
+                }
+            }
+        }
+        Err(self.error(what))
+    }
+
+    /// '+hhmm', '+hh:mm', '+hh' or 'Z'
+    pub(crate) fn offset(&mut self) -> Result<i32, ReadableTimeError> {
+        let what = "utc offset like '+0545'";
+        let sign = match self.rest().as_bytes().first() {
+            Some(b'Z' | b'z') => {
+                self.pos += 1;
+                return Ok(0);
+            }
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            _ => return Err(self.error(what)),
+        };
+        self.pos += 1;
+        let hours = self.ranged(2, 0..=23, "offset hours 00-23")?;
+        if self.rest().starts_with(':') {
+            self.pos += 1;
+        }
+        let minutes = if self.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            self.ranged(2, 0..=59, "offset minutes 00-59")?
+        } else {
+            0
+        };
+        Ok(sign * (hours * 3600 + minutes * 60) as i32)
+    }
+
+    fn run(&mut self, format: &str, out: &mut Parsed) -> Result<(), ReadableTimeError> {
+        let mut chars = format.char_indices();
+        while let Some((at, c)) = chars.next() {
+            if c.is_whitespace() {
+                self.skip_whitespace();
+                continue;
+            }
+            if c != '%' {
+                self.literal(c)?;
+                continue;
+            }
+
+            let mut d = chars.next().map(|(_, c)| c);
+            if matches!(d, Some('-' | '_' | '0')) {
+                d = chars.next().map(|(_, c)| c);
+            }
+            if d == Some(':') {
+                if chars.next().map(|(_, c)| c) != Some('z') {
+                    return Err(ReadableTimeError::UnknownDirective {
+                        directive: ':',
+                        position: at,
+                    });
+                }
+                d = Some('z');
+            }
+            if let Some(digits @ '1'..='9') = d {
+                if chars.next().map(|(_, c)| c) != Some('N') {
+                    return Err(ReadableTimeError::UnknownDirective {
+                        directive: digits,
+                        position: at,
+                    });
+                }
+                let digits = digits as u32 - '0' as u32;
+                let n = self.fixed(
+                    digits as usize,
+                    0..=10i64.pow(digits) - 1,
+                    "fraction digits",
+                )?;
+                out.nanosecond = n as u32 * 10u32.pow(9 - digits);
+                continue;
+            }
+            let Some(d) = d else {
+                return Err(ReadableTimeError::IncompleteDirective { position: at });
+            };
+
+            match d {
+                'Y' => out.year = Some(self.signed(4, "year")?),
+                'C' => out.century = Some(self.number(2, "century")?),
+                'y' => out.year_2digit = Some(self.number(2, "2 digit year")?),
+                'm' => out.month = Some(self.ranged(2, 1..=12, "month 01-12")? as u32),
+                'd' | 'e' => {
+                    self.skip_whitespace();
+                    out.day = Some(self.ranged(2, 1..=31, "day 01-31")? as u32);
+                }
+                'j' => {
+                    out.day_of_year = Some(self.ranged(3, 1..=366, "day of year 001-366")? as u32)
+                }
+                'H' => out.hour_24 = Some(self.ranged(2, 0..=23, "hour 00-23")? as i32),
+                'I' => out.hour_12 = Some(self.ranged(2, 1..=12, "hour 01-12")? as i32),
+                'M' => out.minute = self.ranged(2, 0..=59, "minute 00-59")? as i32,
+                'S' => {
+                    out.second = self.ranged(2, 0..=60, "second 00-60")? as i32;
+                    // a fraction right after the seconds is accepted even when the format
+                    // has none, unless the format reads it itself
+                    let format_next = chars.clone().next().map(|(_, c)| c);
+                    let rest = self.rest().as_bytes();
+                    if matches!(rest, [b'.' | b',', b'0'..=b'9', ..])
+                        && !matches!(format_next, Some('.' | ','))
+                    {
+                        self.pos += 1;
+                        out.nanosecond = self.nanoseconds()?;
+                    }
+                }
+                'N' => out.nanosecond = self.nanoseconds()?,
+                'p' => out.pm = Some(self.name(&["AM", "PM"], "'AM' or 'PM'")? == 1),
+                'a' | 'A' => {
+                    let at = self.pos;
+                    out.week_day = Some((self.name(&WEEKDAY_NAMES, "weekday name")? as u32, at));
+                }
+                'u' => {
+                    let at = self.pos;
+                    out.week_day = Some((self.ranged(1, 1..=7, "weekday 1-7")? as u32 % 7, at));
+                }
+                'w' => {
+                    let at = self.pos;
+                    out.week_day = Some((self.ranged(1, 0..=6, "weekday 0-6")? as u32, at));
+                }
+                'b' | 'B' | 'h' => {
+                    out.month = Some(self.name(&MONTH_NAMES, "month name")? as u32 + 1)
+                }
+                'z' => out.offset = Some(self.offset()?),
+                'Z' => {
+                    let len = self
+                        .rest()
+                        .bytes()
+                        .take_while(u8::is_ascii_alphabetic)
+                        .count();
+                    if len == 0 {
+                        return Err(self.error("time zone name"));
+                    }
+                    let name = &self.rest()[..len];
+                    if let Some(offset) = zone_offset(name) {
+                        out.offset.get_or_insert(offset);
+                    }
+                    out.time_zone = Some(name.to_string());
+                    self.pos += len;
+                }
+                's' => out.timestamp = Some(self.signed(19, "unix timestamp")?),
+                'F' => self.run("%Y-%m-%d", out)?,
+                'T' => self.run("%H:%M:%S", out)?,
+                'R' => self.run("%H:%M", out)?,
+                'n' | 't' => self.skip_whitespace(),
+                '%' => self.literal('%')?,
+                _ => {
+                    return Err(ReadableTimeError::UnknownDirective {
+                        directive: d,
+                        position: at,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Parsed {
+    pub(crate) fn build(self) -> Result<ReadableTime, ReadableTimeError> {
+        let offset = self.offset.unwrap_or(0);
+        let time_zone = match (&self.time_zone, self.offset) {
+            (Some(name), _) => name.clone(),
+            (None, Some(offset)) => offsetf(offset),
+            (None, None) => "UTC".to_string(),
+        };
+        let zone = match offset {
+            0 => Zone::Utc,
+            o => Zone::Fixed(o),
+        };
+
+        if let Some(timestamp) = self.timestamp {
+            let timestamp =
+                time_t::try_from(timestamp).map_err(|_| ReadableTimeError::OutOfRange)?;
+            let mut rt = ReadableTime::from_offset(timestamp, offset, false, time_zone, zone)?;
+            rt.nanosecond = self.nanosecond;
+            return Ok(rt);
+        }
+
+        let year = match (self.year, self.century, self.year_2digit) {
+            (Some(year), _, _) => year,
+            (None, Some(c), yy) => c * 100 + yy.unwrap_or(0),
+            // POSIX: 69-99 are 1969-1999, 00-68 are 2000-2068
+            (None, None, Some(yy)) => yy + if yy >= 69 { 1900 } else { 2000 },
+            (None, None, None) => 1970,
+        };
+
+        let (month, day) = match (self.month, self.day, self.day_of_year) {
+            (None, None, Some(yday)) => {
+                if yday == 366 && !civil::is_leap_year(year) {
+                    return Err(ReadableTimeError::InvalidDayOfYear { year, day: yday });
+                }
+                let (_, m, d) =
+                    civil::civil_from_days(civil::days_from_civil(year, 1, 1) + yday as i64 - 1);
+                (m, d)
+            }
+            (m, d, _) => (m.unwrap_or(1), d.unwrap_or(1)),
+        };
+        if day > civil::days_in_month(year, month) {
+            return Err(ReadableTimeError::InvalidDate { year, month, day });
+        }
+        let days = civil::days_from_civil(year, month, day);
+
+        if let Some((week_day, position)) = self.week_day
+            && week_day != civil::weekday_from_days(days)
+        {
+            return Err(ReadableTimeError::Parse(ParseError {
+                position,
+                expected: "weekday matching the date",
+            }));
+        }
+
+        let hour = match (self.hour_24, self.hour_12, self.pm) {
+            (Some(h), _, _) => h,
+            (None, Some(h), pm) => h % 12 + if pm == Some(true) { 12 } else { 0 },
+            (None, None, _) => 0,
+        };
+
+        let local =
+            days * civil::SECONDS_PER_DAY + (hour * 3600 + self.minute * 60 + self.second) as i64;
+        let timestamp =
+            time_t::try_from(local - offset as i64).map_err(|_| ReadableTimeError::OutOfRange)?;
+        let mut rt = ReadableTime::from_offset(timestamp, offset, false, time_zone, zone)?;
+        rt.nanosecond = self.nanosecond;
+        Ok(rt)
+    }
+}
+
+/// static text for a literal character. the format is user input so only the
+/// separators used by the built in layouts get their own message
+fn quoted(c: char) -> &'static str {
+    match c {
+        ':' => "':'",
+        '-' => "'-'",
+        '/' => "'/'",
+        '.' => "'.'",
+        ',' => "','",
+        ' ' => "' '",
+        'T' => "'T'",
+        'W' => "'W'",
+        '+' => "'+'",
+        '(' => "'('",
+        ')' => "')'",
+        _ => "text matching the format",
+    }
+}
+
+/// offset of "UTC" and the zone names allowed by RFC 2822 (section 4.3)
+pub(crate) fn zone_offset(name: &str) -> Option<i32> {
+    let hours = match name.to_ascii_uppercase().as_str() {
+        "UTC" | "UT" | "GMT" | "Z" => 0,
+        "EST" => -5,
+        "EDT" => -4,
+        "CST" => -6,
+        "CDT" => -5,
+        "MST" => -7,
+        "MDT" => -6,
+        "PST" => -8,
+        "PDT" => -7,
+        _ => return None,
+    };
+    Some(hours * 3600)
+}
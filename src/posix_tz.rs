@@ -0,0 +1,346 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! POSIX TZ strings like "EST5EDT,M3.2.0,M11.1.0" or "<+0545>-5:45".
+//!
+//! These are used by the 'TZ' environment variable and by the footer of TZif v2+ files.
+//! The RFC 8536 (TZif v3) extensions are supported: rule times from -167 to 167 hours.
+
+use std::str::FromStr;
+
+use crate::{ParseError, ReadableTimeError, civil, tzif::LocalTimeType};
+
+/// day of the year a DST rule switches on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleDay {
+    /// 'Jn': 1-365, february 29th is never counted
+    Julian1(u16),
+    /// 'n': 0-365, february 29th is counted in leap years
+    Julian0(u16),
+    /// 'Mm.w.d': day 'd' (0 = Sunday) of week 'w' (1-5, 5 = last) of month 'm'
+    MonthWeekDay { month: u8, week: u8, day: u8 },
+}
+
+/// when DST starts or ends. 'time' is seconds after local midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub day: RuleDay,
+    pub time: i32,
+}
+
+/// the DST part of a POSIX TZ string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dst {
+    pub abbreviation: String,
+    /// seconds east of UTC
+    pub utc_offset: i32,
+    pub start: Rule,
+    pub end: Rule,
+}
+
+/// A parsed POSIX TZ string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixTz {
+    pub std_abbreviation: String,
+    /// seconds east of UTC. NOTE: the string itself uses west-positive offsets
+    pub std_offset: i32,
+    pub dst: Option<Dst>,
+}
+
+/// rule used when a TZ string has a DST name but no rules (same default as glibc)
+const DEFAULT_START: Rule = Rule {
+    day: RuleDay::MonthWeekDay {
+        month: 3,
+        week: 2,
+        day: 0,
+    },
+    time: 7200,
+};
+const DEFAULT_END: Rule = Rule {
+    day: RuleDay::MonthWeekDay {
+        month: 11,
+        week: 1,
+        day: 0,
+    },
+    time: 7200,
+};
+
+impl PosixTz {
+    pub fn parse(tz: &str) -> Result<PosixTz, ReadableTimeError> {
+        let mut p = Parser {
+            s: tz.as_bytes(),
+            pos: 0,
+        };
+
+        let std_abbreviation = p.name()?;
+        let std_offset = -p.offset(24)?;
+        if p.done() {
+            return Ok(PosixTz {
+                std_abbreviation,
+                std_offset,
+                dst: None,
+            });
+        }
+
+        let abbreviation = p.name()?;
+        let utc_offset = if p.done() || p.peek() == Some(b',') {
+            std_offset + 3600
+        } else {
+            -p.offset(24)?
+        };
+        let (start, end) = if p.done() {
+            (DEFAULT_START, DEFAULT_END)
+        } else {
+            p.expect(b',')?;
+            let start = p.rule()?;
+            p.expect(b',')?;
+            let end = p.rule()?;
+            (start, end)
+        };
+        if !p.done() {
+            return Err(p.error("end of TZ string"));
+        }
+
+        Ok(PosixTz {
+            std_abbreviation,
+            std_offset,
+            dst: Some(Dst {
+                abbreviation,
+                utc_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// offset, DST flag and abbreviation in effect at 'timestamp'
+    pub fn local_time_type(&self, timestamp: i64) -> LocalTimeType {
+        let std = LocalTimeType {
+            utc_offset: self.std_offset,
+            is_dst: false,
+            abbreviation: self.std_abbreviation.clone(),
+        };
+        let Some(dst) = &self.dst else {
+            return std;
+        };
+
+        let year = civil::civil_from_days(
+            timestamp
+                .saturating_add(self.std_offset as i64)
+                .div_euclid(civil::SECONDS_PER_DAY),
+        )
+        .0;
+        // start is given in standard local time, end in daylight local time.
+        // saturating so timestamps near the i64 limits still get an answer
+        let start = dst
+            .start
+            .local_seconds(year)
+            .saturating_sub(self.std_offset as i64);
+        let end = dst
+            .end
+            .local_seconds(year)
+            .saturating_sub(dst.utc_offset as i64);
+
+        let in_dst = if start < end {
+            start <= timestamp && timestamp < end
+        } else {
+            // southern hemisphere: DST spans the new year
+            !(end <= timestamp && timestamp < start)
+        };
+
+        if in_dst {
+            LocalTimeType {
+                utc_offset: dst.utc_offset,
+                is_dst: true,
+                abbreviation: dst.abbreviation.clone(),
+            }
+        } else {
+            std
+        }
+    }
+}
+
+impl FromStr for PosixTz {
+    type Err = ReadableTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PosixTz::parse(s)
+    }
+}
+
+impl Rule {
+    /// local seconds since epoch at which this rule fires in 'year'
+    fn local_seconds(&self, year: i64) -> i64 {
+        let jan1 = civil::days_from_civil(year, 1, 1);
+        let days = match self.day {
+            RuleDay::Julian1(n) => {
+                let n = n as i64;
+                let leap_day = (civil::is_leap_year(year) && n >= 60) as i64;
+                jan1 + n - 1 + leap_day
+            }
+            RuleDay::Julian0(n) => jan1 + n as i64,
+            RuleDay::MonthWeekDay { month, week, day } => {
+                let first = civil::days_from_civil(year, month as u32, 1);
+                let first_wd = civil::weekday_from_days(first) as i64;
+                let mut d = first + (day as i64 - first_wd).rem_euclid(7) + (week as i64 - 1) * 7;
+                let month_len = civil::days_in_month(year, month as u32) as i64;
+                while d >= first + month_len {
+                    d -= 7;
+                }
+                d
+            }
+        };
+        days.saturating_mul(civil::SECONDS_PER_DAY)
+            .saturating_add(self.time as i64)
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn done(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn error(&self, expected: &'static str) -> ReadableTimeError {
+        ReadableTimeError::InvalidTzString(ParseError {
+            position: self.pos,
+            expected,
+        })
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), ReadableTimeError> {
+        if self.peek() != Some(c) {
+            return Err(self.error(match c {
+                b',' => "','",
+                b'.' => "'.'",
+                _ => "'>'",
+            }));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// 'EST' or the quoted form '<+0545>'
+    fn name(&mut self) -> Result<String, ReadableTimeError> {
+        let start = self.pos;
+        let name = if self.peek() == Some(b'<') {
+            self.pos += 1;
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'-')
+            {
+                self.pos += 1;
+            }
+            let name = &self.s[start + 1..self.pos];
+            self.expect(b'>')?;
+            name
+        } else {
+            while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                self.pos += 1;
+            }
+            &self.s[start..self.pos]
+        };
+        if name.len() < 3 {
+            self.pos = start;
+            return Err(self.error("zone abbreviation of at least 3 characters"));
+        }
+        Ok(String::from_utf8_lossy(name).to_string())
+    }
+
+    fn number(&mut self, max: i32) -> Result<i32, ReadableTimeError> {
+        let start = self.pos;
+        let mut n: i32 = 0;
+        while let Some(c) = self.peek().filter(u8::is_ascii_digit) {
+            n = n.saturating_mul(10).saturating_add((c - b'0') as i32);
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.error("number"));
+        }
+        if n > max {
+            self.pos = start;
+            return Err(self.error("smaller number"));
+        }
+        Ok(n)
+    }
+
+    /// '[+-]hh[:mm[:ss]]' in seconds. the sign is kept as written
+    fn offset(&mut self, max_hours: i32) -> Result<i32, ReadableTimeError> {
+        let sign = match self.peek() {
+            Some(b'-') => {
+                self.pos += 1;
+                -1
+            }
+            Some(b'+') => {
+                self.pos += 1;
+                1
+            }
+            _ => 1,
+        };
+        let mut secs = self.number(max_hours)? * 3600;
+        if self.peek() == Some(b':') {
+            self.pos += 1;
+            secs += self.number(59)? * 60;
+            if self.peek() == Some(b':') {
+                self.pos += 1;
+                secs += self.number(59)?;
+            }
+        }
+        Ok(sign * secs)
+    }
+
+    fn rule(&mut self) -> Result<Rule, ReadableTimeError> {
+        let day = match self.peek() {
+            Some(b'J') => {
+                self.pos += 1;
+                let start = self.pos;
+                let n = self.number(365)?;
+                if n == 0 {
+                    self.pos = start;
+                    return Err(self.error("julian day 1-365"));
+                }
+                RuleDay::Julian1(n as u16)
+            }
+            Some(b'M') => {
+                self.pos += 1;
+                let month = self.number(12)?;
+                if month == 0 {
+                    return Err(self.error("month 1-12"));
+                }
+                self.expect(b'.')?;
+                let week = self.number(5)?;
+                if week == 0 {
+                    return Err(self.error("week 1-5"));
+                }
+                self.expect(b'.')?;
+                let day = self.number(6)?;
+                RuleDay::MonthWeekDay {
+                    month: month as u8,
+                    week: week as u8,
+                    day: day as u8,
+                }
+            }
+            _ => RuleDay::Julian0(self.number(365)? as u16),
+        };
+        let time = if self.peek() == Some(b'/') {
+            self.pos += 1;
+            self.offset(167)?
+        } else {
+            7200
+        };
+        Ok(Rule { day, time })
+    }
+}
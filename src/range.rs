@@ -0,0 +1,287 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Iterators over dates and times between a start and an end.
+//!
+//! item 'n' is always computed from the start ('start + n * step'), so month steps don't
+//! drift: Jan 31 stepping by a month gives Feb 28, Mar 31, Apr 30.
+//! both ranges are double ended, '.rev()' yields the same items last first.
+
+use std::iter::FusedIterator;
+use std::time::Duration;
+
+use crate::{Date, ReadableTime};
+
+/// How far each item of a 'DateRange' or 'TimeRange' is from the previous one.
+/// a zero step gives an empty range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// calendar days. a 'TimeRange' keeps the wall clock time across DST changes
+    Days(u32),
+    Weeks(u32),
+    /// calendar months, clamped to the last day of shorter months
+    Months(u32),
+    /// exact elapsed time. a 'DateRange' rounds it up to whole days,
+    /// so 36 hours steps by 2 days and 1 hour by 1 day
+    Exact(Duration),
+}
+
+impl Default for Step {
+    fn default() -> Self {
+        Step::Days(1)
+    }
+}
+
+impl Step {
+    fn is_zero(self) -> bool {
+        match self {
+            Step::Days(k) | Step::Weeks(k) | Step::Months(k) => k == 0,
+            Step::Exact(d) => d.is_zero(),
+        }
+    }
+}
+
+/// Dates from 'start' to 'end', daily and excluding 'end' unless changed.
+///
+/// ```
+/// # use readable_time::*;
+/// # fn main() -> Result<(), ReadableTimeError> {
+/// // every monday of Q4 2025
+/// let start = Date::new(2025, Month::October, 1)?;
+/// let end = Date::new(2025, Month::December, 31)?;
+/// let mondays: Vec<Date> = DateRange::new(start, end)
+///     .inclusive()
+///     .filter(|d| d.week_day() == Weekday::Monday)
+///     .collect();
+/// assert_eq!(mondays.len(), 13);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateRange(Range<Date>);
+
+impl DateRange {
+    pub fn new(start: Date, end: Date) -> DateRange {
+        DateRange(Range::new(start, end))
+    }
+
+    /// set the step. restarts the range
+    pub fn step(self, step: Step) -> DateRange {
+        DateRange(Range { step, ..self.0 }.restart())
+    }
+
+    /// yield 'end' too if a step lands on it. restarts the range
+    pub fn inclusive(self) -> DateRange {
+        DateRange(
+            Range {
+                inclusive: true,
+                ..self.0
+            }
+            .restart(),
+        )
+    }
+}
+
+/// Times from 'start' to 'end' in the zone of 'start', daily and excluding 'end' unless changed.
+/// 'end' is compared as an instant so it can be in any zone
+///
+/// calendar steps keep the wall clock time, exact steps keep the elapsed time:
+/// over the start of DST in New York 'Step::Days(1)' from 09:00 gives 09:00 every day,
+/// 'Step::Exact' of an hour from 00:00 gives 00:00, 01:00, 03:00 (EDT)
+#[derive(Debug, Clone)]
+pub struct TimeRange(Range<ReadableTime>);
+
+impl TimeRange {
+    pub fn new(start: ReadableTime, end: ReadableTime) -> TimeRange {
+        TimeRange(Range::new(start, end))
+    }
+
+    /// set the step. restarts the range
+    pub fn step(self, step: Step) -> TimeRange {
+        TimeRange(Range { step, ..self.0 }.restart())
+    }
+
+    /// yield 'end' too if a step lands on it. restarts the range
+    pub fn inclusive(self) -> TimeRange {
+        TimeRange(
+            Range {
+                inclusive: true,
+                ..self.0
+            }
+            .restart(),
+        )
+    }
+}
+
+macro_rules! range_iterator {
+    ($range:ty, $item:ty) => {
+        impl Iterator for $range {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl DoubleEndedIterator for $range {
+            fn next_back(&mut self) -> Option<$item> {
+                self.0.next_back()
+            }
+        }
+
+        impl FusedIterator for $range {}
+    };
+}
+
+range_iterator!(DateRange, Date);
+range_iterator!(TimeRange, ReadableTime);
+
+/// something a 'Range' can step over
+trait Stepped: Sized + PartialOrd {
+    /// 'self' moved by 'n' steps, None when out of range
+    fn nth_step(&self, step: Step, n: u64) -> Option<Self>;
+}
+
+impl Stepped for Date {
+    fn nth_step(&self, step: Step, n: u64) -> Option<Date> {
+        let n = i64::try_from(n).ok()?;
+        let date = match step {
+            Step::Days(k) => self.add_days(n.checked_mul(k as i64)?),
+            Step::Weeks(k) => self.add_days(n.checked_mul(k as i64 * 7)?),
+            Step::Months(k) => self.add_months(n.checked_mul(k as i64)?),
+            Step::Exact(d) => {
+                let days = d.as_nanos().div_ceil(86_400 * 1_000_000_000);
+                self.add_days(n.checked_mul(i64::try_from(days).ok()?)?)
+            }
+        };
+        date.ok()
+    }
+}
+
+impl Stepped for ReadableTime {
+    fn nth_step(&self, step: Step, n: u64) -> Option<ReadableTime> {
+        let n_i64 = i64::try_from(n).ok()?;
+        let rt = match step {
+            Step::Days(k) => self.add_days(n_i64.checked_mul(k as i64)?),
+            Step::Weeks(k) => self.add_days(n_i64.checked_mul(k as i64 * 7)?),
+            Step::Months(k) => self.add_months(n_i64.checked_mul(k as i64)?),
+            Step::Exact(d) => self.add_duration(times(d, n)?),
+        };
+        rt.ok()
+    }
+}
+
+/// the state shared by 'DateRange' and 'TimeRange'
+#[derive(Debug, Clone)]
+struct Range<T> {
+    start: T,
+    end: T,
+    step: Step,
+    inclusive: bool,
+    /// index of the next item from the front
+    front: u64,
+    /// index after the last item
+    back: u64,
+}
+
+impl<T: Stepped> Range<T> {
+    fn new(start: T, end: T) -> Range<T> {
+        Range {
+            start,
+            end,
+            step: Step::default(),
+            inclusive: false,
+            front: 0,
+            back: 0,
+        }
+        .restart()
+    }
+
+    /// back to the first item, counting the items again
+    fn restart(self) -> Range<T> {
+        let back = count(|n| self.item(n).is_some());
+        Range {
+            front: 0,
+            back,
+            ..self
+        }
+    }
+
+    /// item 'n' counted from the start, None past the end
+    fn item(&self, n: u64) -> Option<T> {
+        if self.step.is_zero() {
+            return None;
+        }
+        self.start.nth_step(self.step, n).filter(|item| {
+            if self.inclusive {
+                *item <= self.end
+            } else {
+                *item < self.end
+            }
+        })
+    }
+
+    fn next(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        self.item(self.front - 1)
+    }
+
+    fn next_back(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.item(self.back)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = usize::try_from(self.back - self.front).ok();
+        (left.unwrap_or(usize::MAX), left)
+    }
+}
+
+/// number of items. 'fits(n)' is true for every n below the count and false from it on,
+/// found with a doubling search followed by a binary search
+fn count(fits: impl Fn(u64) -> bool) -> u64 {
+    if !fits(0) {
+        return 0;
+    }
+    // fits(lo) is true, fits(hi) is false
+    let mut lo = 0;
+    let mut hi = 1;
+    while fits(hi) {
+        lo = hi;
+        hi = match hi.checked_mul(2) {
+            Some(h) => h,
+            None => return u64::MAX,
+        };
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if fits(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+/// 'd * n', None on overflow
+fn times(d: Duration, n: u64) -> Option<Duration> {
+    let nanos = d.as_nanos().checked_mul(n as u128)?;
+    let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
@@ -0,0 +1,133 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Humanized relative time like "5 minutes ago" or "in 3 weeks".
+
+use crate::{ReadableTime, ReadableTimeError, time_since_epoch};
+
+/// average gregorian month and year in seconds
+const MONTH: i64 = 2_629_746;
+const YEAR: i64 = 31_556_952;
+
+/// how a fractional count like 1.6 hours is turned into a whole number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// 1.6 hours -> "1 hour"
+    Floor,
+    /// 1.5 hours -> "2 hours"
+    #[default]
+    Round,
+    /// 1.1 hours -> "2 hours"
+    Ceil,
+}
+
+/// Thresholds used by 'relative_to_with'.
+/// each unit is used while the rounded count stays below its threshold, then the next
+/// bigger unit is tried. the defaults are the same as moment.js
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeOptions {
+    /// differences below this many seconds are "just now"
+    pub just_now: i64,
+    pub seconds: i64,
+    pub minutes: i64,
+    pub hours: i64,
+    pub days: i64,
+    pub weeks: i64,
+    pub months: i64,
+    pub rounding: Rounding,
+}
+
+impl Default for RelativeOptions {
+    fn default() -> Self {
+        RelativeOptions {
+            just_now: 10,
+            seconds: 45,
+            minutes: 45,
+            hours: 22,
+            days: 7,
+            weeks: 4,
+            months: 11,
+            rounding: Rounding::Round,
+        }
+    }
+}
+
+impl ReadableTime {
+    /// 'self' described relative to 'other' with the default thresholds.
+    /// EXAMPLE: "just now", "5 minutes ago", "yesterday", "in 3 weeks"
+    pub fn relative_to(&self, other: &ReadableTime) -> String {
+        self.relative_to_with(other, &RelativeOptions::default())
+    }
+
+    /// same as 'relative_to' with custom thresholds and rounding.
+    /// "yesterday" and "tomorrow" are only used for the calendar day before or after 'other'
+    /// in the zone of 'other', otherwise a day count is printed
+    pub fn relative_to_with(&self, other: &ReadableTime, opts: &RelativeOptions) -> String {
+        let days_apart = self
+            .in_zone(&other.zone)
+            .ok()
+            .map(|s| s.date().to_days() - other.date().to_days());
+        humanize(
+            self.unix_timestamp() - other.unix_timestamp(),
+            days_apart,
+            opts,
+        )
+    }
+
+    /// 'self' relative to the current time
+    pub fn humanize_since_now(&self) -> Result<String, ReadableTimeError> {
+        let now = self.zone.at(time_since_epoch()?)?;
+        Ok(self.relative_to(&now))
+    }
+}
+
+/// 'diff' is positive for the future. 'days_apart' is the difference of the calendar dates
+fn humanize(diff: i64, days_apart: Option<i64>, opts: &RelativeOptions) -> String {
+    let secs = diff.abs();
+    if secs < opts.just_now {
+        return "just now".to_string();
+    }
+
+    let units = [
+        (1, opts.seconds, "second"),
+        (60, opts.minutes, "minute"),
+        (3600, opts.hours, "hour"),
+        (86_400, opts.days, "day"),
+        (604_800, opts.weeks, "week"),
+        (MONTH, opts.months, "month"),
+    ];
+    let (count, unit) = units
+        .iter()
+        .map(|&(size, threshold, name)| (divide(secs, size, opts.rounding), threshold, name))
+        .find(|&(count, threshold, _)| count < threshold)
+        .map(|(count, _, name)| (count, name))
+        .unwrap_or((divide(secs, YEAR, opts.rounding), "year"));
+    let count = count.max(1);
+
+    match (unit, days_apart) {
+        ("day", Some(-1)) => "yesterday".to_string(),
+        ("day", Some(1)) => "tomorrow".to_string(),
+        _ => {
+            let future = diff > 0;
+            let plural = if count == 1 { "" } else { "s" };
+            if future {
+                format!("in {count} {unit}{plural}")
+            } else {
+                format!("{count} {unit}{plural} ago")
+            }
+        }
+    }
+}
+
+fn divide(secs: i64, size: i64, rounding: Rounding) -> i64 {
+    match rounding {
+        Rounding::Floor => secs / size,
+        Rounding::Round => (secs + size / 2) / size,
+        Rounding::Ceil => (secs + size - 1) / size,
+    }
+}
@@ -0,0 +1,231 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! RFC 2822 (email 'Date:') and RFC 9110 HTTP-date formats.
+//!
+//! HTTP-date has three forms and recipients must accept all of them:
+//!
+//! - IMF-fixdate: 'Sun, 06 Nov 1994 08:49:37 GMT'
+//! - obsolete RFC 850: 'Sunday, 06-Nov-94 08:49:37 GMT'
+//! - asctime: 'Sun Nov  6 08:49:37 1994'
+
+use crate::{
+    ReadableTime, ReadableTimeError,
+    format::{MONTH_NAMES, WEEKDAY_NAMES},
+    get_readable_time_utc,
+    parse::{Parsed, Parser, zone_offset},
+};
+
+impl ReadableTime {
+    /// RFC 2822 date for email headers
+    /// EXAMPLE: Sun, 30 Nov 2025 07:14:00 +0545
+    pub fn to_rfc2822(&self) -> Result<String, ReadableTimeError> {
+        self.format("%a, %-d %b %Y %H:%M:%S %z")
+    }
+
+    /// IMF-fixdate for HTTP headers like 'Last-Modified'. always in GMT
+    /// EXAMPLE: Sun, 06 Nov 1994 08:49:37 GMT
+    pub fn to_http_date(&self) -> Result<String, ReadableTimeError> {
+        self.to_utc()?.format("%a, %d %b %Y %H:%M:%S GMT")
+    }
+
+    /// obsolete RFC 850 date. always in GMT
+    /// EXAMPLE: Sunday, 06-Nov-94 08:49:37 GMT
+    pub fn to_rfc850(&self) -> Result<String, ReadableTimeError> {
+        self.to_utc()?.format("%A, %d-%b-%y %H:%M:%S GMT")
+    }
+
+    /// C 'asctime' layout of the fields as they are. use 'to_utc' first for HTTP
+    /// EXAMPLE: Sun Nov  6 08:49:37 1994
+    pub fn to_asctime(&self) -> Result<String, ReadableTimeError> {
+        self.format("%a %b %e %H:%M:%S %Y")
+    }
+
+    /// Lenient RFC 2822 parser.
+    /// accepts the obsolete syntax too: missing weekday, missing seconds, 2 or 3 digit years,
+    /// zone names like "GMT" or "EST", extra whitespace and a trailing comment.
+    /// military zones ("A"-"Z") are taken as UTC as RFC 2822 recommends
+    /// EXAMPLE: Sun, 30 Nov 2025 07:14:00 +0545
+    pub fn parse_rfc2822(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let mut out = Parsed::default();
+
+        p.skip_whitespace();
+        if p.rest().starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let at = p.pos;
+            out.week_day = Some((p.name(&WEEKDAY_NAMES, "weekday name")? as u32, at));
+            p.skip_whitespace();
+            p.literal(',')?;
+            p.skip_whitespace();
+        }
+        out.day = Some(p.ranged(2, 1..=31, "day 1-31")? as u32);
+        p.skip_whitespace();
+        out.month = Some(p.name(&MONTH_NAMES, "month name")? as u32 + 1);
+        p.skip_whitespace();
+        out.year = Some(year(&mut p)?);
+        p.skip_whitespace();
+        time(&mut p, &mut out)?;
+        p.skip_whitespace();
+        zone(&mut p, &mut out)?;
+        p.skip_whitespace();
+        if p.rest().starts_with('(') && p.rest().ends_with(')') {
+            p.pos = input.len();
+        }
+        finish(&p, out)
+    }
+
+    /// Parse any of the three HTTP-date forms. the result is in GMT
+    /// EXAMPLE: Sun, 06 Nov 1994 08:49:37 GMT
+    pub fn parse_http_date(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        match input.find(',') {
+            Some(3) => Self::parse_imf_fixdate(input),
+            Some(_) => Self::parse_rfc850(input),
+            None => Self::parse_asctime(input),
+        }
+    }
+
+    /// 'Sun, 06 Nov 1994 08:49:37 GMT'
+    fn parse_imf_fixdate(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let mut out = Parsed::default();
+        let at = p.pos;
+        out.week_day = Some((p.name(&WEEKDAY_NAMES, "weekday name")? as u32, at));
+        p.literal(',')?;
+        p.literal(' ')?;
+        out.day = Some(p.fixed(2, 1..=31, "day 01-31")? as u32);
+        p.literal(' ')?;
+        out.month = Some(p.name(&MONTH_NAMES, "month name")? as u32 + 1);
+        p.literal(' ')?;
+        out.year = Some(p.fixed(4, 0..=9999, "4 digit year")?);
+        p.literal(' ')?;
+        time(&mut p, &mut out)?;
+        gmt(&mut p, &mut out)?;
+        finish(&p, out)
+    }
+
+    /// 'Sunday, 06-Nov-94 08:49:37 GMT'.
+    /// like RFC 9110 says a 2 digit year more than 50 years in the future is moved back a century
+    fn parse_rfc850(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let mut out = Parsed::default();
+        let at = p.pos;
+        out.week_day = Some((p.name(&WEEKDAY_NAMES, "weekday name")? as u32, at));
+        p.literal(',')?;
+        p.literal(' ')?;
+        out.day = Some(p.fixed(2, 1..=31, "day 01-31")? as u32);
+        p.literal('-')?;
+        out.month = Some(p.name(&MONTH_NAMES, "month name")? as u32 + 1);
+        p.literal('-')?;
+        let yy = p.fixed(2, 0..=99, "2 digit year")?;
+        let this_year = get_readable_time_utc()
+            .map(|rt| rt.year as i64)
+            .unwrap_or(1970);
+        let mut year = this_year - this_year.rem_euclid(100) + yy;
+        if year > this_year + 50 {
+            year -= 100;
+        }
+        out.year = Some(year);
+        p.literal(' ')?;
+        time(&mut p, &mut out)?;
+        gmt(&mut p, &mut out)?;
+        finish(&p, out)
+    }
+
+    /// C 'asctime' layout: 'Sun Nov  6 08:49:37 1994'.
+    /// a zone before the year is allowed so the output of 'get_extended_ptimef'
+    /// ('Sun Nov 30 07:14:00 +0545 2025') is accepted too. without a zone the time is GMT
+    pub fn parse_asctime(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let mut out = Parsed::default();
+        let at = p.pos;
+        out.week_day = Some((p.name(&WEEKDAY_NAMES, "weekday name")? as u32, at));
+        p.skip_whitespace();
+        out.month = Some(p.name(&MONTH_NAMES, "month name")? as u32 + 1);
+        p.skip_whitespace();
+        out.day = Some(p.ranged(2, 1..=31, "day 1-31")? as u32);
+        p.skip_whitespace();
+        time(&mut p, &mut out)?;
+        p.skip_whitespace();
+        if !p.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            zone(&mut p, &mut out)?;
+            p.skip_whitespace();
+        } else {
+            out.time_zone = Some("GMT".to_string());
+            out.offset = Some(0);
+        }
+        out.year = Some(p.fixed(4, 0..=9999, "4 digit year")?);
+        finish(&p, out)
+    }
+}
+
+/// 4 digit year, or the obsolete 2 and 3 digit forms of RFC 2822 (section 4.3)
+fn year(p: &mut Parser) -> Result<i64, ReadableTimeError> {
+    let start = p.pos;
+    let year = p.number(9, "year")?;
+    Ok(match p.pos - start {
+        2 if year < 50 => year + 2000,
+        2 | 3 => year + 1900,
+        _ => year,
+    })
+}
+
+/// 'hh:mm' or 'hh:mm:ss'
+fn time(p: &mut Parser, out: &mut Parsed) -> Result<(), ReadableTimeError> {
+    out.hour_24 = Some(p.ranged(2, 0..=23, "hour 00-23")? as i32);
+    p.literal(':')?;
+    out.minute = p.ranged(2, 0..=59, "minute 00-59")? as i32;
+    if p.rest().starts_with(':') {
+        p.pos += 1;
+        out.second = p.ranged(2, 0..=60, "second 00-60")? as i32;
+    }
+    Ok(())
+}
+
+/// '+hhmm' or a zone name
+fn zone(p: &mut Parser, out: &mut Parsed) -> Result<(), ReadableTimeError> {
+    if p.rest().starts_with(['+', '-']) {
+        let negative = p.rest().starts_with('-');
+        p.pos += 1;
+        let h = p.fixed(2, 0..=23, "offset hours 00-23")?;
+        let m = p.fixed(2, 0..=59, "offset minutes 00-59")?;
+        let offset = (h * 3600 + m * 60) as i32;
+        out.offset = Some(if negative { -offset } else { offset });
+        return Ok(());
+    }
+
+    let len = p.rest().bytes().take_while(u8::is_ascii_alphabetic).count();
+    let name = p.rest()[..len].to_ascii_uppercase();
+    let offset = match zone_offset(&name) {
+        Some(offset) => offset,
+        // military zones. RFC 2822 says to treat them as '-0000'
+        None if len == 1 && name != "J" => 0,
+        None => return Err(p.error("utc offset like '+0545' or zone name like 'GMT'")),
+    };
+    p.pos += len;
+    out.offset = Some(offset);
+    out.time_zone = Some(name);
+    Ok(())
+}
+
+fn gmt(p: &mut Parser, out: &mut Parsed) -> Result<(), ReadableTimeError> {
+    p.literal(' ')?;
+    if !p.rest().starts_with("GMT") {
+        return Err(p.error("'GMT'"));
+    }
+    p.pos += 3;
+    out.offset = Some(0);
+    out.time_zone = Some("GMT".to_string());
+    Ok(())
+}
+
+fn finish(p: &Parser, out: Parsed) -> Result<ReadableTime, ReadableTimeError> {
+    if !p.rest().is_empty() {
+        return Err(p.error("end of input"));
+    }
+    out.build()
+}
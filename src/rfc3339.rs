@@ -0,0 +1,256 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! RFC 3339 formatting and parsing, plus the common ISO 8601 date/time forms.
+
+use crate::{ReadableTime, ReadableTimeError, Zone, civil, offsetf, parse::Parser, time_t};
+
+impl ReadableTime {
+    /// RFC 3339 timestamp with whole seconds. a zero offset is written as 'Z'.
+    /// returns 'OutOfRange' for years outside 0000-9999, which RFC 3339 can't write
+    /// EXAMPLE: 2025-11-30T07:14:00+05:45
+    pub fn to_rfc3339(&self) -> Result<String, ReadableTimeError> {
+        self.to_rfc3339_opts(0, true)
+    }
+
+    /// RFC 3339 timestamp with 'precision' (0-9) fractional second digits, truncated.
+    /// 'use_z' writes a zero offset as 'Z' instead of '+00:00'.
+    /// returns 'OutOfRange' for years outside 0000-9999
+    /// EXAMPLE: 2025-11-30T01:29:00.123+00:00
+    pub fn to_rfc3339_opts(
+        &self,
+        precision: usize,
+        use_z: bool,
+    ) -> Result<String, ReadableTimeError> {
+        if !(0..=9999).contains(&self.year) {
+            return Err(ReadableTimeError::OutOfRange);
+        }
+        let mut out = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year,
+            self.month.number(),
+            self.day,
+            self.hour_24,
+            self.minute,
+            self.second
+        );
+        if precision > 0 {
+            let frac = format!("{:09}", self.nanosecond);
+            out.push('.');
+            out.push_str(&frac[..precision.min(9)]);
+        }
+        if use_z && self.utc_offset_seconds == 0 {
+            out.push('Z');
+        } else {
+            let offset = offsetf(self.utc_offset_seconds);
+            out.push_str(&offset[..3]);
+            out.push(':');
+            out.push_str(&offset[3..]);
+        }
+        Ok(out)
+    }
+
+    /// Parse the RFC 3339 profile of ISO 8601.
+    /// 'T' may also be 't' or a space. fractional seconds are kept to the nanosecond,
+    /// more digits are truncated
+    /// EXAMPLE: 2025-11-30T07:14:00+05:45, 1985-04-12T23:20:50.52Z
+    pub fn parse_rfc3339(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let year = p.fixed(4, 0..=9999, "4 digit year")?;
+        p.literal('-')?;
+        let month = p.fixed(2, 1..=12, "month 01-12")? as u32;
+        p.literal('-')?;
+        let day = day_in_month(&mut p, year, month)?;
+        match p.rest().as_bytes().first() {
+            Some(b'T' | b't' | b' ') => p.pos += 1,
+            _ => return Err(p.error("'T'")),
+        }
+        let hour = p.fixed(2, 0..=23, "hour 00-23")?;
+        p.literal(':')?;
+        let minute = p.fixed(2, 0..=59, "minute 00-59")?;
+        p.literal(':')?;
+        let second = p.fixed(2, 0..=60, "second 00-60")?;
+        let nanosecond = fraction(&mut p, false)?;
+
+        let offset = match p.rest().as_bytes().first() {
+            Some(b'Z' | b'z') => {
+                p.pos += 1;
+                0
+            }
+            Some(b'+' | b'-') => {
+                let negative = p.rest().starts_with('-');
+                p.pos += 1;
+                let h = p.fixed(2, 0..=23, "offset hours 00-23")?;
+                p.literal(':')?;
+                let m = p.fixed(2, 0..=59, "offset minutes 00-59")?;
+                let offset = (h * 3600 + m * 60) as i32;
+                if negative { -offset } else { offset }
+            }
+            _ => return Err(p.error("'Z' or utc offset like '+05:45'")),
+        };
+        if !p.rest().is_empty() {
+            return Err(p.error("end of input"));
+        }
+
+        let days = civil::days_from_civil(year, month, day);
+        build(
+            days,
+            hour * 3600 + minute * 60 + second,
+            nanosecond,
+            Some(offset),
+        )
+    }
+
+    /// Parse common ISO 8601 forms:
+    ///
+    /// - calendar dates: '2025-11-30', '20251130', '2025-11'
+    /// - week dates: '2025-W48-7', '2025W487', '2025-W48'
+    /// - ordinal dates: '2025-334', '2025334'
+    ///
+    /// optionally followed by 'T' and a time in extended ('07:14:00') or basic ('071400') form
+    /// with optional fractional seconds ('.5' or ',5'), and an offset ('Z', '+05:45', '+0545', '+05').
+    /// '24:00:00' is accepted as the end of the day. without an offset the time is taken as UTC.
+    pub fn parse_iso8601(input: &str) -> Result<ReadableTime, ReadableTimeError> {
+        let mut p = Parser { input, pos: 0 };
+        let year = p.fixed(4, 0..=9999, "4 digit year")?;
+        let extended = p.rest().starts_with('-');
+        if extended {
+            p.pos += 1;
+        }
+
+        let days = if p.rest().starts_with('W') {
+            p.pos += 1;
+            let week_at = p.pos;
+            let week = p.fixed(2, 1..=53, "week 01-53")?;
+            if week as u32 > civil::iso_weeks_in_year(year) {
+                p.pos = week_at;
+                return Err(p.error("week that exists in the year"));
+            }
+            let has_day = if extended {
+                p.rest().starts_with('-')
+            } else {
+                p.rest().starts_with(|c: char| c.is_ascii_digit())
+            };
+            let week_day = if has_day {
+                if extended {
+                    p.pos += 1;
+                }
+                p.fixed(1, 1..=7, "weekday 1-7")?
+            } else {
+                1
+            };
+            civil::days_from_iso_week(year, week as u32, week_day as u32)
+        } else {
+            let digits = p.rest().bytes().take_while(u8::is_ascii_digit).count();
+            match (extended, digits) {
+                (_, 3) => {
+                    let at = p.pos;
+                    let yday = p.fixed(3, 1..=366, "day of year 001-366")?;
+                    if yday == 366 && !civil::is_leap_year(year) {
+                        p.pos = at;
+                        return Err(p.error("day of year 001-365"));
+                    }
+                    civil::days_from_civil(year, 1, 1) + yday - 1
+                }
+                (true, 2) => {
+                    let month = p.fixed(2, 1..=12, "month 01-12")? as u32;
+                    let day = if p.rest().starts_with('-') {
+                        p.pos += 1;
+                        day_in_month(&mut p, year, month)?
+                    } else {
+                        1
+                    };
+                    civil::days_from_civil(year, month, day)
+                }
+                (false, 4) => {
+                    let month = p.fixed(2, 1..=12, "month 01-12")? as u32;
+                    let day = day_in_month(&mut p, year, month)?;
+                    civil::days_from_civil(year, month, day)
+                }
+                _ => return Err(p.error("month, week or day of year")),
+            }
+        };
+
+        let mut secs = 0;
+        let mut nanosecond = 0;
+        let mut offset = None;
+        if let Some(b'T' | b't' | b' ') = p.rest().as_bytes().first() {
+            p.pos += 1;
+            let hour_at = p.pos;
+            let hour = p.fixed(2, 0..=24, "hour 00-24")?;
+            let mut minute = 0;
+            let mut second = 0;
+            let colon = p.rest().starts_with(':');
+            if colon || p.rest().starts_with(|c: char| c.is_ascii_digit()) {
+                if colon {
+                    p.pos += 1;
+                }
+                minute = p.fixed(2, 0..=59, "minute 00-59")?;
+                let colon = p.rest().starts_with(':');
+                if colon || p.rest().starts_with(|c: char| c.is_ascii_digit()) {
+                    if colon {
+                        p.pos += 1;
+                    }
+                    second = p.fixed(2, 0..=60, "second 00-60")?;
+                    nanosecond = fraction(&mut p, true)?;
+                }
+            }
+            if hour == 24 && (minute != 0 || second != 0 || nanosecond != 0) {
+                p.pos = hour_at;
+                return Err(p.error("hour 00-23. 24 is only valid as 24:00:00"));
+            }
+            secs = hour * 3600 + minute * 60 + second;
+            if !p.rest().is_empty() {
+                offset = Some(p.offset()?);
+            }
+        }
+        if !p.rest().is_empty() {
+            return Err(p.error("end of input"));
+        }
+
+        build(days, secs, nanosecond, offset)
+    }
+}
+
+fn day_in_month(p: &mut Parser, year: i64, month: u32) -> Result<u32, ReadableTimeError> {
+    let at = p.pos;
+    let day = p.fixed(2, 1..=31, "day 01-31")? as u32;
+    if day > civil::days_in_month(year, month) {
+        p.pos = at;
+        return Err(p.error("day that exists in the month"));
+    }
+    Ok(day)
+}
+
+/// '.digits' (or ',digits' when 'comma' is set) as nanoseconds. 0 without a fraction
+fn fraction(p: &mut Parser, comma: bool) -> Result<u32, ReadableTimeError> {
+    let sep = p.rest().starts_with('.') || (comma && p.rest().starts_with(','));
+    if !sep {
+        return Ok(0);
+    }
+    p.pos += 1;
+    p.nanoseconds()
+}
+
+fn build(
+    days: i64,
+    secs: i64,
+    nanosecond: u32,
+    offset: Option<i32>,
+) -> Result<ReadableTime, ReadableTimeError> {
+    let offset_seconds = offset.unwrap_or(0);
+    let (time_zone, zone) = match offset_seconds {
+        0 => ("UTC".to_string(), Zone::Utc),
+        o => (offsetf(o), Zone::Fixed(o)),
+    };
+    let timestamp = time_t::try_from(days * civil::SECONDS_PER_DAY + secs - offset_seconds as i64)
+        .map_err(|_| ReadableTimeError::OutOfRange)?;
+    let mut rt = ReadableTime::from_offset(timestamp, offset_seconds, false, time_zone, zone)?;
+    rt.nanosecond = nanosecond;
+    Ok(rt)
+}
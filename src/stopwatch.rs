@@ -0,0 +1,203 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Stopwatch with laps, and a guard that reports how long a step took when dropped.
+
+use std::time::{Duration, Instant};
+
+use crate::{DurationFormat, TimeUnit};
+
+/// where a 'StepTimer' sends its line
+type Sink = Box<dyn FnMut(&str) + Send>;
+
+/// Short form for measured time.
+/// below a second it is whole milli or microseconds, below a minute seconds with 2 decimals,
+/// above that the 2 biggest units
+/// EXAMPLE: "850µs", "350ms", "1.24s", "2m 5s", "1h 3m"
+pub fn format_elapsed(d: Duration) -> String {
+    if d < Duration::from_millis(1) {
+        format!("{}µs", d.as_micros())
+    } else if d < Duration::from_secs(1) {
+        format!("{}ms", d.as_millis())
+    } else if d < Duration::from_secs(60) {
+        // truncate instead of rounding so "59.999s" never shows as "60.00s"
+        format!("{}.{:02}s", d.as_secs(), d.subsec_millis() / 10)
+    } else {
+        DurationFormat {
+            smallest: TimeUnit::Second,
+            precision: Some(2),
+            ..DurationFormat::default()
+        }
+        .format(d)
+    }
+}
+
+/// A stopwatch on the monotonic clock ('Instant'). stopping keeps the elapsed time,
+/// 'resume' continues from it
+#[derive(Debug, Clone, Default)]
+pub struct Stopwatch {
+    /// set while running
+    running_since: Option<Instant>,
+    /// time measured before the current run
+    stored: Duration,
+    laps: Vec<Duration>,
+    /// 'elapsed' at the end of the last lap
+    lap_mark: Duration,
+}
+
+impl Stopwatch {
+    /// stopped at zero
+    pub fn new() -> Stopwatch {
+        Stopwatch::default()
+    }
+
+    /// running from now
+    pub fn start_new() -> Stopwatch {
+        let mut sw = Stopwatch::new();
+        sw.start();
+        sw
+    }
+
+    /// reset and run from zero
+    pub fn start(&mut self) {
+        *self = Stopwatch {
+            running_since: Some(Instant::now()),
+            ..Stopwatch::default()
+        };
+    }
+
+    /// stop and return the total elapsed time. does nothing if already stopped
+    pub fn stop(&mut self) -> Duration {
+        if let Some(since) = self.running_since.take() {
+            self.stored += since.elapsed();
+        }
+        self.stored
+    }
+
+    /// continue after 'stop'. does nothing if already running
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    /// stop and clear the elapsed time and laps
+    pub fn reset(&mut self) {
+        *self = Stopwatch::default();
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// total time measured, not counting the time while stopped
+    pub fn elapsed(&self) -> Duration {
+        self.stored + self.running_since.map_or(Duration::ZERO, |s| s.elapsed())
+    }
+
+    /// end the current lap and return its length
+    pub fn lap(&mut self) -> Duration {
+        let now = self.elapsed();
+        let lap = now - self.lap_mark;
+        self.lap_mark = now;
+        self.laps.push(lap);
+        lap
+    }
+
+    /// lengths of the finished laps, first lap first
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// 'elapsed' as text
+    /// EXAMPLE: "1.24s"
+    pub fn elapsedf(&self) -> String {
+        format_elapsed(self.elapsed())
+    }
+
+    /// one line per lap with its length and the total at its end
+    /// EXAMPLE:
+    /// lap 1: 1.24s (total 1.24s)
+    /// lap 2: 350ms (total 1.59s)
+    pub fn report(&self) -> String {
+        let mut total = Duration::ZERO;
+        let mut out = String::new();
+        for (i, lap) in self.laps.iter().enumerate() {
+            total += *lap;
+            out.push_str(&format!(
+                "lap {}: {} (total {})\n",
+                i + 1,
+                format_elapsed(*lap),
+                format_elapsed(total)
+            ));
+        }
+        out
+    }
+}
+
+/// Reports "step <name> took <time>" to its sink when dropped.
+/// the default sink is stderr
+///
+/// ```
+/// # use readable_time::*;
+/// {
+///     let _step = StepTimer::new("load config");
+///     // ... work ...
+/// } // prints "step load config took 1.24s"
+/// ```
+pub struct StepTimer {
+    name: String,
+    start: Instant,
+    sink: Option<Sink>,
+}
+
+impl StepTimer {
+    /// starts timing now
+    pub fn new(name: impl Into<String>) -> StepTimer {
+        StepTimer {
+            name: name.into(),
+            start: Instant::now(),
+            sink: Some(Box::new(|line| eprintln!("{line}"))),
+        }
+    }
+
+    /// send the line somewhere else, like a logger or a 'Vec'
+    pub fn with_sink(mut self, sink: impl FnMut(&str) + Send + 'static) -> StepTimer {
+        self.sink = Some(Box::new(sink));
+        self
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// report now instead of at the end of the scope
+    pub fn finish(self) -> Duration {
+        let elapsed = self.elapsed();
+        drop(self);
+        elapsed
+    }
+}
+
+impl Drop for StepTimer {
+    fn drop(&mut self) {
+        let line = format!("step {} took {}", self.name, format_elapsed(self.elapsed()));
+        if let Some(mut sink) = self.sink.take() {
+            sink(&line);
+        }
+    }
+}
+
+impl std::fmt::Debug for StepTimer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StepTimer")
+            .field("name", &self.name)
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
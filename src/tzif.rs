@@ -0,0 +1,348 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Parser for TZif (zoneinfo) files as described in RFC 8536.
+//!
+//! Versions 1, 2 and 3 are supported. For v2+ files only the 64-bit data block is used.
+//! Leap second records are read past but ignored ('right/' zones are not supported).
+
+use std::{path::Path, sync::Arc};
+
+use crate::{ReadableTimeError, posix_tz::PosixTz};
+
+/// default location of the compiled IANA database. overridden by the 'TZDIR' env var
+pub const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// one entry of the local time type table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeType {
+    /// seconds east of UTC
+    pub utc_offset: i32,
+    pub is_dst: bool,
+    pub abbreviation: String,
+}
+
+/// the instant at which 'local_type' starts to apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition {
+    /// unix timestamp
+    pub time: i64,
+    /// index into 'TimeZone::local_types'
+    pub local_type: usize,
+}
+
+/// A time zone loaded from TZif data. cheap to clone, the tables are shared
+#[derive(Debug, Clone)]
+pub struct TimeZone {
+    name: String,
+    transitions: Arc<[Transition]>,
+    local_types: Arc<[LocalTimeType]>,
+    footer: Option<String>,
+    rule: Option<PosixTz>,
+}
+
+impl TimeZone {
+    /// load an IANA zone by name like "Asia/Kathmandu" or "America/New_York"
+    pub fn named(name: &str) -> Result<TimeZone, ReadableTimeError> {
+        if name.is_empty()
+            || name.starts_with('/')
+            || name.split('/').any(|part| part == ".." || part == ".")
+        {
+            return Err(ReadableTimeError::InvalidTimeZoneName);
+        }
+        let dir = std::env::var("TZDIR").unwrap_or_else(|_| ZONEINFO_DIR.to_string());
+        let bytes = std::fs::read(Path::new(&dir).join(name))?;
+        Self::from_tzif(name, &bytes)
+    }
+
+    /// load a TZif file from any path. the path is used as the zone name
+    pub fn from_file(path: impl AsRef<Path>) -> Result<TimeZone, ReadableTimeError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        Self::from_tzif(&path.to_string_lossy(), &bytes)
+    }
+
+    /// parse TZif data from a byte slice
+    pub fn from_tzif(name: &str, bytes: &[u8]) -> Result<TimeZone, ReadableTimeError> {
+        let mut r = Reader { bytes, pos: 0 };
+        let v1 = Header::read(&mut r)?;
+        if v1.version == 0 {
+            let (transitions, local_types) = v1.read_block(&mut r, 4)?;
+            return Ok(TimeZone {
+                name: name.to_string(),
+                transitions: transitions.into(),
+                local_types: local_types.into(),
+                footer: None,
+                rule: None,
+            });
+        }
+
+        r.skip(v1.block_len(4))?;
+        let v2 = Header::read(&mut r)?;
+        let (transitions, local_types) = v2.read_block(&mut r, 8)?;
+
+        if r.take(1)? != b"\n" {
+            return Err(ReadableTimeError::InvalidTzif("footer expected newline"));
+        }
+        let rest = &bytes[r.pos..];
+        let end = rest
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or(ReadableTimeError::InvalidTzif(
+                "footer missing closing newline",
+            ))?;
+        let footer = std::str::from_utf8(&rest[..end])
+            .map_err(|_| ReadableTimeError::InvalidTzif("footer not ascii"))?
+            .to_string();
+        let rule = if footer.is_empty() {
+            None
+        } else {
+            Some(PosixTz::parse(&footer)?)
+        };
+
+        Ok(TimeZone {
+            name: name.to_string(),
+            transitions: transitions.into(),
+            local_types: local_types.into(),
+            footer: if footer.is_empty() {
+                None
+            } else {
+                Some(footer)
+            },
+            rule,
+        })
+    }
+
+    /// zone described only by a POSIX TZ string like "EST5EDT,M3.2.0,M11.1.0"
+    pub fn from_posix(tz: &str) -> Result<TimeZone, ReadableTimeError> {
+        let rule = PosixTz::parse(tz)?;
+        Ok(TimeZone {
+            name: tz.to_string(),
+            transitions: Arc::new([]),
+            local_types: Arc::new([rule.local_time_type(0)]),
+            footer: Some(tz.to_string()),
+            rule: Some(rule),
+        })
+    }
+
+    /// zone for a value of the 'TZ' environment variable.
+    /// like glibc a zoneinfo file is tried first ('Asia/Kathmandu', ':Asia/Kathmandu')
+    /// and then the value is parsed as a POSIX TZ string
+    pub fn from_tz(value: &str) -> Result<TimeZone, ReadableTimeError> {
+        let name = value.strip_prefix(':').unwrap_or(value);
+        if name.starts_with('/') {
+            return Self::from_file(name);
+        }
+        match Self::named(name) {
+            Ok(tz) => Ok(tz),
+            Err(e) if value.starts_with(':') => Err(e),
+            Err(_) => Self::from_posix(value),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// transitions sorted by time
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    pub fn local_types(&self) -> &[LocalTimeType] {
+        &self.local_types
+    }
+
+    /// POSIX TZ string from the v2+ footer, like "EST5EDT,M3.2.0,M11.1.0"
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    /// parsed form of 'footer'
+    pub fn rule(&self) -> Option<&PosixTz> {
+        self.rule.as_ref()
+    }
+
+    /// local time type in effect at 'timestamp'.
+    /// after the last transition the footer rule is used when there is one
+    pub fn local_time_type(&self, timestamp: i64) -> LocalTimeType {
+        let idx = self.transitions.partition_point(|t| t.time <= timestamp);
+        match &self.rule {
+            Some(rule) if idx == self.transitions.len() => rule.local_time_type(timestamp),
+            // RFC 8536: type 0 is used before the first transition
+            _ if idx == 0 => self.local_types[0].clone(),
+            _ => self.local_types[self.transitions[idx - 1].local_type].clone(),
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ReadableTimeError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(ReadableTimeError::InvalidTzif("unexpected end of data"))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn skip(&mut self, n: usize) -> Result<(), ReadableTimeError> {
+        self.take(n).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8, ReadableTimeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ReadableTimeError> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn time(&mut self, size: usize) -> Result<i64, ReadableTimeError> {
+        let b = self.take(size)?;
+        Ok(match size {
+            4 => i32::from_be_bytes([b[0], b[1], b[2], b[3]]) as i64,
+            _ => i64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]),
+        })
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(r: &mut Reader) -> Result<Header, ReadableTimeError> {
+        if r.take(4)? != b"TZif" {
+            return Err(ReadableTimeError::InvalidTzif("missing 'TZif' magic"));
+        }
+        let version = match r.u8()? {
+            0 => 0,
+            b'2' => 2,
+            b'3' => 3,
+            // RFC 8536 says readers should accept newer versions as v3
+            v if v > b'3' => 3,
+            _ => return Err(ReadableTimeError::InvalidTzif("unknown version")),
+        };
+        r.skip(15)?;
+        let h = Header {
+            version,
+            isutcnt: r.u32()? as usize,
+            isstdcnt: r.u32()? as usize,
+            leapcnt: r.u32()? as usize,
+            timecnt: r.u32()? as usize,
+            typecnt: r.u32()? as usize,
+            charcnt: r.u32()? as usize,
+        };
+        if h.typecnt == 0 || h.charcnt == 0 {
+            return Err(ReadableTimeError::InvalidTzif(
+                "typecnt and charcnt must not be zero",
+            ));
+        }
+        if (h.isutcnt != 0 && h.isutcnt != h.typecnt)
+            || (h.isstdcnt != 0 && h.isstdcnt != h.typecnt)
+        {
+            return Err(ReadableTimeError::InvalidTzif(
+                "isutcnt/isstdcnt must be zero or typecnt",
+            ));
+        }
+        Ok(h)
+    }
+
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+
+    fn read_block(
+        &self,
+        r: &mut Reader,
+        time_size: usize,
+    ) -> Result<(Vec<Transition>, Vec<LocalTimeType>), ReadableTimeError> {
+        // check before allocating so a corrupt header can not request huge buffers
+        if r.bytes.len() - r.pos < self.block_len(time_size) {
+            return Err(ReadableTimeError::InvalidTzif("unexpected end of data"));
+        }
+        let mut times = Vec::with_capacity(self.timecnt);
+        for _ in 0..self.timecnt {
+            times.push(r.time(time_size)?);
+        }
+        if times.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ReadableTimeError::InvalidTzif(
+                "transition times are not sorted",
+            ));
+        }
+
+        let mut transitions = Vec::with_capacity(self.timecnt);
+        for time in times {
+            let local_type = r.u8()? as usize;
+            if local_type >= self.typecnt {
+                return Err(ReadableTimeError::InvalidTzif(
+                    "transition type index out of range",
+                ));
+            }
+            transitions.push(Transition { time, local_type });
+        }
+
+        let mut raw_types = Vec::with_capacity(self.typecnt);
+        for _ in 0..self.typecnt {
+            let utc_offset = r.u32()? as i32;
+            let is_dst = match r.u8()? {
+                0 => false,
+                1 => true,
+                _ => return Err(ReadableTimeError::InvalidTzif("isdst must be 0 or 1")),
+            };
+            let abbr_idx = r.u8()? as usize;
+            if utc_offset == i32::MIN {
+                return Err(ReadableTimeError::InvalidTzif("utoff must not be -2^31"));
+            }
+            raw_types.push((utc_offset, is_dst, abbr_idx));
+        }
+
+        let chars = r.take(self.charcnt)?;
+        let mut local_types = Vec::with_capacity(self.typecnt);
+        for (utc_offset, is_dst, abbr_idx) in raw_types {
+            let rest = chars.get(abbr_idx..).ok_or(ReadableTimeError::InvalidTzif(
+                "abbreviation index out of range",
+            ))?;
+            let len = rest
+                .iter()
+                .position(|&b| b == 0)
+                .ok_or(ReadableTimeError::InvalidTzif(
+                    "abbreviation is not NUL terminated",
+                ))?;
+            local_types.push(LocalTimeType {
+                utc_offset,
+                is_dst,
+                abbreviation: String::from_utf8_lossy(&rest[..len]).to_string(),
+            });
+        }
+
+        r.skip(self.leapcnt * (time_size + 4) + self.isstdcnt + self.isutcnt)?;
+        Ok((transitions, local_types))
+    }
+}
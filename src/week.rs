@@ -0,0 +1,51 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Week numbers: ISO 8601 week dates and the sunday or monday first weeks of '%U' and '%W'.
+
+use crate::{Date, ReadableTime, ReadableTimeError, Weekday, Zone, civil};
+
+impl ReadableTime {
+    /// ISO 8601 week date as (iso year, week 1-53, weekday).
+    /// weeks start on monday and week 1 is the one with the first thursday of the year,
+    /// so the iso year can differ from 'year' around january 1st
+    /// EXAMPLE: 2024-12-30 is (2025, 1, Monday), 2021-01-03 is (2020, 53, Sunday)
+    pub fn iso_week(&self) -> (i32, u32, Weekday) {
+        let days = civil::days_from_civil(self.year as i64, self.month.number(), self.day as u32);
+        let (year, week, _) = civil::iso_week_from_days(days);
+        (year as i32, week, self.week_day)
+    }
+
+    /// week of the year 0-53 with weeks starting on sunday, same as '%U'.
+    /// days before the first sunday are in week 0
+    pub fn sunday_week(&self) -> u32 {
+        (self.day_of_year as u32 + 6 - self.week_day.to_c()) / 7
+    }
+
+    /// week of the year 0-53 with weeks starting on monday, same as '%W'.
+    /// days before the first monday are in week 0
+    pub fn monday_week(&self) -> u32 {
+        (self.day_of_year as u32 + 6 - (self.week_day.to_iso() - 1)) / 7
+    }
+
+    /// Midnight in 'zone' at an ISO 8601 week date. inverse of 'iso_week'.
+    /// if midnight is skipped by a DST change the first time after the gap is used
+    /// EXAMPLE: (2025, 1, Weekday::Monday) is 2024-12-30
+    pub fn from_iso_week_date(
+        iso_year: i32,
+        week: u32,
+        weekday: Weekday,
+        zone: &Zone,
+    ) -> Result<ReadableTime, ReadableTimeError> {
+        let year = iso_year as i64;
+        if week == 0 || week > civil::iso_weeks_in_year(year) {
+            return Err(ReadableTimeError::InvalidIsoWeek { year, week });
+        }
+        Date::from_days(civil::days_from_iso_week(year, week, weekday.to_iso()))?.start_of_day(zone)
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! Day of the week.
+
+use std::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{Case, ParseError, ReadableTimeError, names::WEEKDAYS};
+
+/// A day of the week. the order (and 'Ord') starts at sunday like C's 'tm_wday'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    /// sunday first
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    /// next day. saturday is followed by sunday
+    pub fn succ(self) -> Weekday {
+        self + 1
+    }
+
+    /// previous day. sunday is preceded by saturday
+    pub fn pred(self) -> Weekday {
+        self - 1
+    }
+
+    /// C numbering (0 = sunday .. 6 = saturday) like 'tm_wday' and '%w'
+    pub fn to_c(self) -> u32 {
+        self as u32
+    }
+
+    /// ISO 8601 numbering (1 = monday .. 7 = sunday) like '%u'
+    pub fn to_iso(self) -> u32 {
+        (self as u32 + 6) % 7 + 1
+    }
+
+    /// 0 = sunday .. 6 = saturday
+    pub fn from_c(n: u32) -> Option<Weekday> {
+        Self::ALL.get(n as usize).copied()
+    }
+
+    /// 1 = monday .. 7 = sunday
+    pub fn from_iso(n: u32) -> Option<Weekday> {
+        match n {
+            1..=7 => Some(Self::ALL[n as usize % 7]),
+            _ => None,
+        }
+    }
+
+    /// days to go forward from 'other' to reach 'self', 0-6
+    /// EXAMPLE: Weekday::Friday.days_since(Weekday::Monday) is 4
+    pub fn days_since(self, other: Weekday) -> u32 {
+        (self as u32 + 7 - other as u32) % 7
+    }
+
+    /// "Sun"
+    pub fn short_name(self) -> &'static str {
+        self.short_name_in(Case::Title)
+    }
+
+    /// "Sunday"
+    pub fn long_name(self) -> &'static str {
+        self.long_name_in(Case::Title)
+    }
+
+    /// "Sun", "SUN" or "sun"
+    pub fn short_name_in(self, case: Case) -> &'static str {
+        &self.long_name_in(case)[..3]
+    }
+
+    /// "Sunday", "SUNDAY" or "sunday"
+    pub fn long_name_in(self, case: Case) -> &'static str {
+        WEEKDAYS[case as usize][self as usize]
+    }
+}
+
+/// 'n' days later, wrapping around the week
+impl Add<i64> for Weekday {
+    type Output = Weekday;
+
+    fn add(self, days: i64) -> Weekday {
+        Self::ALL[(self as i64 + days.rem_euclid(7)).rem_euclid(7) as usize]
+    }
+}
+
+impl Sub<i64> for Weekday {
+    type Output = Weekday;
+
+    fn sub(self, days: i64) -> Weekday {
+        Self::ALL[(self as i64 - days.rem_euclid(7)).rem_euclid(7) as usize]
+    }
+}
+
+/// short or long english name, case insensitive. "sun", "Sunday"
+impl FromStr for Weekday {
+    type Err = ReadableTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|d| {
+                s.eq_ignore_ascii_case(d.short_name()) || s.eq_ignore_ascii_case(d.long_name())
+            })
+            .ok_or(ReadableTimeError::Parse(ParseError {
+                position: 0,
+                expected: "weekday name",
+            }))
+    }
+}
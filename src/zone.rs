@@ -0,0 +1,84 @@
+/*
+ * readable_time
+ * Copyright (c) 2025 BayonetArch
+ *
+ * This software is released under the MIT License.
+ * See LICENSE file for details.
+ */
+
+//! The rules a 'ReadableTime' was converted with, so a changed time can be converted back
+//! to the same zone.
+
+use crate::{ReadableTime, ReadableTimeError, TimeZone, time_t};
+
+#[derive(Debug, Clone)]
+pub enum Zone {
+    /// the host zone, through 'localtime_r'
+    Local,
+    Utc,
+    /// seconds east of UTC
+    Fixed(i32),
+    Tz(TimeZone),
+}
+
+impl Zone {
+    /// convert a unix timestamp to a 'ReadableTime' in this zone
+    pub fn at(&self, timestamp: time_t) -> Result<ReadableTime, ReadableTimeError> {
+        match self {
+            Zone::Local => ReadableTime::from_timestamp(timestamp),
+            Zone::Utc => ReadableTime::utc_from_timestamp(timestamp),
+            Zone::Fixed(offset) => ReadableTime::from_timestamp_with_offset(timestamp, *offset),
+            Zone::Tz(tz) => ReadableTime::from_timestamp_in(timestamp, tz),
+        }
+    }
+
+    /// utc offset in effect at 'timestamp'
+    pub fn offset_at(&self, timestamp: i64) -> Result<i32, ReadableTimeError> {
+        match self {
+            Zone::Local => {
+                let timestamp =
+                    time_t::try_from(timestamp).map_err(|_| ReadableTimeError::OutOfRange)?;
+                Ok(ReadableTime::from_timestamp(timestamp)?.utc_offset_seconds)
+            }
+            Zone::Utc => Ok(0),
+            Zone::Fixed(offset) if offset.unsigned_abs() >= 86_400 => {
+                Err(ReadableTimeError::InvalidOffset(*offset))
+            }
+            Zone::Fixed(offset) => Ok(*offset),
+            Zone::Tz(tz) => Ok(tz.local_time_type(timestamp).utc_offset),
+        }
+    }
+
+    /// Unix timestamp of a wall clock time given as seconds since 1970-01-01 00:00 local.
+    /// a time repeated by a backward shift (end of DST) resolves to the earlier instant,
+    /// a time skipped by a forward shift is moved forward by the length of the gap
+    /// EXAMPLE: 02:30 on the day New York starts DST becomes 03:30 EDT
+    pub fn resolve(&self, local: i64) -> Result<time_t, ReadableTimeError> {
+        let shift = |a: i64, b: i64| a.checked_sub(b).ok_or(ReadableTimeError::OutOfRange);
+        let before = self.offset_at(shift(local, 86_400)?)?;
+        let after = self.offset_at(shift(local, -86_400)?)?;
+        let mut found = None;
+        for offset in [before, after] {
+            let t = shift(local, offset as i64)?;
+            if self.offset_at(t)? == offset {
+                found = Some(found.map_or(t, |f: i64| f.min(t)));
+            }
+        }
+        let t = match found {
+            Some(t) => t,
+            None => shift(local, before as i64)?,
+        };
+        time_t::try_from(t).map_err(|_| ReadableTimeError::OutOfRange)
+    }
+}
+
+impl ReadableTime {
+    /// same instant converted to 'zone'
+    pub fn in_zone(&self, zone: &Zone) -> Result<ReadableTime, ReadableTimeError> {
+        let timestamp =
+            time_t::try_from(self.unix_timestamp()).map_err(|_| ReadableTimeError::OutOfRange)?;
+        let mut rt = zone.at(timestamp)?;
+        rt.nanosecond = self.nanosecond;
+        Ok(rt)
+    }
+}
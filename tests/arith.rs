@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use readable_time::*;
+
+mod common;
+use common::new_york;
+
+fn utc(s: &str) -> ReadableTime {
+    ReadableTime::parse_rfc3339(s).unwrap()
+}
+
+#[test]
+fn month_overflow_policies() {
+    let jan31 = utc("2025-01-31T12:00:00Z");
+    assert_eq!(
+        jan31.add_months(1).unwrap().get_timef(),
+        "2025-02-28 12:00:00"
+    );
+    assert_eq!(
+        jan31
+            .add_months_with(1, MonthOverflow::Overflow)
+            .unwrap()
+            .get_timef(),
+        "2025-03-03 12:00:00"
+    );
+    assert!(jan31.add_months_with(1, MonthOverflow::Error).is_err());
+    assert_eq!(
+        jan31
+            .add_months_with(2, MonthOverflow::Error)
+            .unwrap()
+            .get_timef(),
+        "2025-03-31 12:00:00"
+    );
+
+    let leap = utc("2024-01-31T00:00:00Z");
+    assert_eq!(
+        leap.add_months(1).unwrap().get_timef(),
+        "2024-02-29 00:00:00"
+    );
+    assert_eq!(
+        leap.add_months_with(1, MonthOverflow::Overflow)
+            .unwrap()
+            .get_timef(),
+        "2024-03-02 00:00:00"
+    );
+}
+
+#[test]
+fn years_and_negative_months() {
+    let feb29 = utc("2024-02-29T08:00:00Z");
+    assert_eq!(
+        feb29.add_years(1).unwrap().get_timef(),
+        "2025-02-28 08:00:00"
+    );
+    assert_eq!(
+        feb29.add_years(4).unwrap().get_timef(),
+        "2028-02-29 08:00:00"
+    );
+    assert_eq!(
+        feb29
+            .add_years_with(1, MonthOverflow::Overflow)
+            .unwrap()
+            .get_timef(),
+        "2025-03-01 08:00:00"
+    );
+    assert_eq!(
+        feb29.sub_years(1).unwrap().get_timef(),
+        "2023-02-28 08:00:00"
+    );
+
+    let mar31 = utc("2025-03-31T00:00:00Z");
+    assert_eq!(
+        mar31.sub_months(1).unwrap().get_timef(),
+        "2025-02-28 00:00:00"
+    );
+    assert_eq!(
+        mar31.sub_months(13).unwrap().get_timef(),
+        "2024-02-29 00:00:00"
+    );
+    assert_eq!(
+        mar31.add_months(-3).unwrap().get_timef(),
+        "2024-12-31 00:00:00"
+    );
+}
+
+#[test]
+fn days_rederive_fields() {
+    let rt = utc("2025-12-31T23:30:00Z").add_days(1).unwrap();
+    assert_eq!(rt.get_timef(), "2026-01-01 23:30:00");
+    assert_eq!(rt.week_day, Weekday::Thursday);
+    assert_eq!(rt.day_of_year, 1);
+    assert_eq!(rt.hour_12, 11);
+
+    let back = rt.sub_days(366).unwrap();
+    assert_eq!(back.get_timef(), "2024-12-31 23:30:00");
+    assert_eq!(back.week_day, Weekday::Tuesday);
+    assert_eq!(back.day_of_year, 366);
+}
+
+#[test]
+fn duration_rederives_hour_12() {
+    let rt = utc("2025-01-01T11:30:00Z");
+    let noon = rt.add_duration(Duration::from_secs(3600)).unwrap();
+    assert_eq!(noon.hour_12, 12);
+    assert_eq!(noon.format("%p").unwrap(), "PM");
+
+    let next = rt
+        .add_duration(Duration::from_secs(13 * 3600 + 1800))
+        .unwrap();
+    assert_eq!(next.get_timef(), "2025-01-02 01:00:00");
+    assert_eq!(next.hour_12, 1);
+    assert_eq!(next.week_day, Weekday::Thursday);
+    assert_eq!(
+        next.sub_duration(Duration::from_secs(13 * 3600 + 1800))
+            .unwrap()
+            .get_timef(),
+        rt.get_timef()
+    );
+}
+
+#[test]
+fn keeps_fixed_offset() {
+    let rt = ReadableTime::parse_rfc3339("2025-11-30T07:14:00+05:45").unwrap();
+    let next = rt.add_months(1).unwrap();
+    assert_eq!(next.to_rfc3339().unwrap(), "2025-12-30T07:14:00+05:45");
+    let next = rt.add_duration(Duration::from_secs(86_400)).unwrap();
+    assert_eq!(next.to_rfc3339().unwrap(), "2025-12-01T07:14:00+05:45");
+}
+
+#[test]
+fn days_keep_wall_clock_over_dst() {
+    // 2025-03-08 09:00 EST, the day before DST starts
+    let rt = new_york(1_741_442_400);
+    assert_eq!(
+        rt.get_extended_ptimef().unwrap(),
+        "Sat Mar 8 09:00:00 -0500 2025"
+    );
+
+    let next = rt.add_days(1).unwrap();
+    assert_eq!(
+        next.get_extended_ptimef().unwrap(),
+        "Sun Mar 9 09:00:00 -0400 2025"
+    );
+    assert_eq!(next.time_zone, "EDT");
+    assert!(next.is_dst);
+
+    // 24 hours of elapsed time is 10:00 on the wall clock
+    let next = rt.add_duration(Duration::from_secs(86_400)).unwrap();
+    assert_eq!(
+        next.get_extended_ptimef().unwrap(),
+        "Sun Mar 9 10:00:00 -0400 2025"
+    );
+}
+
+#[test]
+fn dst_gap_and_overlap() {
+    // 2025-03-08 02:30 EST. 02:30 does not exist the next day
+    let rt = new_york(1_741_419_000);
+    let next = rt.add_days(1).unwrap();
+    assert_eq!(
+        next.get_extended_ptimef().unwrap(),
+        "Sun Mar 9 03:30:00 -0400 2025"
+    );
+
+    // 2025-11-01 01:30 EDT. 01:30 happens twice the next day, the first one is used
+    let rt = new_york(1_761_975_000);
+    assert_eq!(rt.get_timef(), "2025-11-01 01:30:00");
+    let next = rt.add_days(1).unwrap();
+    assert_eq!(
+        next.get_extended_ptimef().unwrap(),
+        "Sun Nov 2 01:30:00 -0400 2025"
+    );
+    let later = next.add_duration(Duration::from_secs(3600)).unwrap();
+    assert_eq!(
+        later.get_extended_ptimef().unwrap(),
+        "Sun Nov 2 01:30:00 -0500 2025"
+    );
+}
+
+#[test]
+fn huge_counts_are_out_of_range() {
+    let rt = utc("2025-11-30T07:14:09Z");
+    for months in [
+        i64::MAX / 2,
+        i64::MIN / 2,
+        i64::MAX - 12,
+        12 * (i32::MAX as i64),
+    ] {
+        assert_eq!(
+            rt.add_months(months).unwrap_err(),
+            ReadableTimeError::OutOfRange,
+            "{months}"
+        );
+    }
+    for years in [i64::MAX / 24, i64::MIN / 24, i32::MAX as i64] {
+        assert_eq!(
+            rt.add_years(years).unwrap_err(),
+            ReadableTimeError::OutOfRange,
+            "{years}"
+        );
+    }
+    assert_eq!(
+        rt.add_years(i64::MAX).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    assert_eq!(
+        rt.add_days(i64::MAX / 2).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+}
+
+#[test]
+fn resolve_extreme_local_times() {
+    for zone in [Zone::Utc, Zone::Fixed(-3600), Zone::Fixed(20_700)] {
+        for local in [i64::MAX, i64::MIN] {
+            assert_eq!(
+                zone.resolve(local).unwrap_err(),
+                ReadableTimeError::OutOfRange,
+                "{zone:?} {local}"
+            );
+        }
+        // close to the limits it may or may not fit, but never panics
+        let _ = zone.resolve(i64::MAX - 86_400);
+        let _ = zone.resolve(i64::MIN + 86_400);
+    }
+}
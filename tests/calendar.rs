@@ -0,0 +1,233 @@
+use std::process::Command;
+
+use readable_time::*;
+
+// NOTE: the snapshots below are written by hand. cal(1) was not installed where they were
+// written (Debian 12, util-linux 2.38.1 without its cal), so they are the expected layout of
+// util-linux cal, not captured output, and the centering of the 'year' title is unconfirmed.
+// 'matches_util_linux_cal' compares them with the real `cal 11 2025`, `cal -wm 12 2025`,
+// `cal -3 11 2025` and `cal 2025`. it is ignored by default, run it where a util-linux cal
+// is on the PATH with `cargo test --test calendar -- --ignored` and replace a snapshot with
+// the real output if it fails.
+
+#[test]
+fn month() {
+    let expected = "   November 2025
+Su Mo Tu We Th Fr Sa
+                   1
+ 2  3  4  5  6  7  8
+ 9 10 11 12 13 14 15
+16 17 18 19 20 21 22
+23 24 25 26 27 28 29
+30
+";
+    assert_eq!(Calendar::default().month(2025, Month::November), expected);
+}
+
+#[test]
+fn monday_first_with_iso_weeks() {
+    let cal = Calendar {
+        first_weekday: Weekday::Monday,
+        week_numbers: true,
+        ..Calendar::default()
+    };
+    // the last row is week 1 of 2026
+    let expected = "     December 2025
+   Mo Tu We Th Fr Sa Su
+49  1  2  3  4  5  6  7
+50  8  9 10 11 12 13 14
+51 15 16 17 18 19 20 21
+52 22 23 24 25 26 27 28
+ 1 29 30 31
+
+";
+    assert_eq!(cal.month(2025, Month::December), expected);
+
+    // week 53 of 2020 at the start of january 2021
+    let expected = "     January 2021
+   Mo Tu We Th Fr Sa Su
+53              1  2  3
+ 1  4  5  6  7  8  9 10
+ 2 11 12 13 14 15 16 17
+ 3 18 19 20 21 22 23 24
+ 4 25 26 27 28 29 30 31
+
+";
+    assert_eq!(cal.month(2021, Month::January), expected);
+}
+
+#[test]
+fn quarter() {
+    let expected = "    October 2025         November 2025         December 2025
+Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa
+          1  2  3  4                     1      1  2  3  4  5  6
+ 5  6  7  8  9 10 11   2  3  4  5  6  7  8   7  8  9 10 11 12 13
+12 13 14 15 16 17 18   9 10 11 12 13 14 15  14 15 16 17 18 19 20
+19 20 21 22 23 24 25  16 17 18 19 20 21 22  21 22 23 24 25 26 27
+26 27 28 29 30 31     23 24 25 26 27 28 29  28 29 30 31
+                      30
+";
+    for month in [Month::October, Month::November, Month::December] {
+        assert_eq!(Calendar::default().quarter(2025, month), expected);
+    }
+}
+
+#[test]
+fn year() {
+    let expected = "                              2025
+
+      January               February               March
+Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa
+          1  2  3  4                     1                     1
+ 5  6  7  8  9 10 11   2  3  4  5  6  7  8   2  3  4  5  6  7  8
+12 13 14 15 16 17 18   9 10 11 12 13 14 15   9 10 11 12 13 14 15
+19 20 21 22 23 24 25  16 17 18 19 20 21 22  16 17 18 19 20 21 22
+26 27 28 29 30 31     23 24 25 26 27 28     23 24 25 26 27 28 29
+                                            30 31
+
+       April                  May                   June
+Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa
+       1  2  3  4  5               1  2  3   1  2  3  4  5  6  7
+ 6  7  8  9 10 11 12   4  5  6  7  8  9 10   8  9 10 11 12 13 14
+13 14 15 16 17 18 19  11 12 13 14 15 16 17  15 16 17 18 19 20 21
+20 21 22 23 24 25 26  18 19 20 21 22 23 24  22 23 24 25 26 27 28
+27 28 29 30           25 26 27 28 29 30 31  29 30
+
+
+        July                 August              September
+Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa
+       1  2  3  4  5                  1  2      1  2  3  4  5  6
+ 6  7  8  9 10 11 12   3  4  5  6  7  8  9   7  8  9 10 11 12 13
+13 14 15 16 17 18 19  10 11 12 13 14 15 16  14 15 16 17 18 19 20
+20 21 22 23 24 25 26  17 18 19 20 21 22 23  21 22 23 24 25 26 27
+27 28 29 30 31        24 25 26 27 28 29 30  28 29 30
+                      31
+
+      October               November              December
+Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa
+          1  2  3  4                     1      1  2  3  4  5  6
+ 5  6  7  8  9 10 11   2  3  4  5  6  7  8   7  8  9 10 11 12 13
+12 13 14 15 16 17 18   9 10 11 12 13 14 15  14 15 16 17 18 19 20
+19 20 21 22 23 24 25  16 17 18 19 20 21 22  21 22 23 24 25 26 27
+26 27 28 29 30 31     23 24 25 26 27 28 29  28 29 30 31
+                      30
+";
+    assert_eq!(Calendar::default().year(2025), expected);
+}
+
+#[test]
+fn localized() {
+    let cal = Calendar {
+        first_weekday: Weekday::Monday,
+        locale: Locale::DE_DE,
+        ..Calendar::default()
+    };
+    let expected = "     März 2025
+Mo Di Mi Do Fr Sa So
+                1  2
+ 3  4  5  6  7  8  9
+10 11 12 13 14 15 16
+17 18 19 20 21 22 23
+24 25 26 27 28 29 30
+31
+";
+    assert_eq!(cal.month(2025, Month::March), expected);
+
+    // each japanese weekday is one character but two columns wide
+    let cal = Calendar {
+        locale: Locale::JA_JP,
+        ..Calendar::default()
+    };
+    let expected = "      2月 2025
+日 月 火 水 木 金 土
+                   1
+ 2  3  4  5  6  7  8
+ 9 10 11 12 13 14 15
+16 17 18 19 20 21 22
+23 24 25 26 27 28
+
+";
+    assert_eq!(cal.month(2025, Month::February), expected);
+}
+
+#[test]
+fn highlight_today() {
+    let rt = ReadableTime::parse("2025-11-12", "%Y-%m-%d").unwrap();
+    let cal = Calendar {
+        style: CalendarStyle::Ansi,
+        today: Some((rt.year, rt.month, rt.day)),
+        ..Calendar::default()
+    };
+    let out = cal.month(2025, Month::November);
+    assert!(
+        out.contains(" 9 10 11 \x1b[7m12\x1b[0m 13 14 15\n"),
+        "{out:?}"
+    );
+    // only the highlighted month is affected
+    assert!(!cal.month(2025, Month::October).contains('\x1b'));
+
+    let plain = Calendar {
+        style: CalendarStyle::Plain,
+        ..cal
+    };
+    assert_eq!(
+        plain.month(2025, Month::November),
+        Calendar::default().month(2025, Month::November)
+    );
+}
+
+/// output of util-linux cal with trailing spaces and blank lines removed, None without one
+fn util_linux_cal(args: &[&str]) -> Option<(String, String)> {
+    let run = |args: &[&str]| {
+        Command::new("cal")
+            .args(args)
+            .env("LC_ALL", "C")
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+    };
+    let version = run(&["--version"])?;
+    if !version.contains("util-linux") {
+        return None;
+    }
+    Some((normalize(&run(args)?), version.trim().to_string()))
+}
+
+fn normalize(s: &str) -> String {
+    let lines: Vec<&str> = s.lines().map(str::trim_end).collect();
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(0, |i| i + 1);
+    lines[..end].join("\n")
+}
+
+#[test]
+#[ignore = "needs util-linux cal(1) on the PATH"]
+fn matches_util_linux_cal() {
+    let monday_weeks = Calendar {
+        first_weekday: Weekday::Monday,
+        week_numbers: true,
+        ..Calendar::default()
+    };
+    let cases = [
+        (
+            vec!["11", "2025"],
+            Calendar::default().month(2025, Month::November),
+        ),
+        (
+            vec!["-wm", "12", "2025"],
+            monday_weeks.month(2025, Month::December),
+        ),
+        (
+            vec!["-3", "11", "2025"],
+            Calendar::default().quarter(2025, Month::November),
+        ),
+        (vec!["2025"], Calendar::default().year(2025)),
+    ];
+    for (args, ours) in cases {
+        let (real, version) = util_linux_cal(&args).expect("util-linux cal not found");
+        assert_eq!(normalize(&ours), real, "cal {} ({version})", args.join(" "));
+    }
+}
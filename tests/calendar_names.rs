@@ -0,0 +1,99 @@
+use readable_time::*;
+
+#[test]
+fn weekday_numbering() {
+    for (i, d) in Weekday::ALL.into_iter().enumerate() {
+        assert_eq!(d.to_c(), i as u32);
+        assert_eq!(Weekday::from_c(d.to_c()), Some(d));
+        assert_eq!(Weekday::from_iso(d.to_iso()), Some(d));
+    }
+    assert_eq!(Weekday::Monday.to_iso(), 1);
+    assert_eq!(Weekday::Sunday.to_iso(), 7);
+    assert_eq!(Weekday::from_c(7), None);
+    assert_eq!(Weekday::from_iso(0), None);
+}
+
+#[test]
+fn weekday_arithmetic() {
+    assert_eq!(Weekday::Saturday.succ(), Weekday::Sunday);
+    assert_eq!(Weekday::Sunday.pred(), Weekday::Saturday);
+    assert_eq!(Weekday::Monday + 10, Weekday::Thursday);
+    assert_eq!(Weekday::Monday - 8, Weekday::Sunday);
+    assert_eq!(Weekday::Monday + -1, Weekday::Sunday);
+    assert_eq!(
+        Weekday::Wednesday + i64::MAX,
+        Weekday::Wednesday + i64::MAX % 7
+    );
+    assert_eq!(Weekday::Friday.days_since(Weekday::Monday), 4);
+    assert_eq!(Weekday::Monday.days_since(Weekday::Friday), 3);
+}
+
+#[test]
+fn month_numbering_and_arithmetic() {
+    for (i, m) in Month::ALL.into_iter().enumerate() {
+        assert_eq!(m.number(), i as u32 + 1);
+        assert_eq!(m.to_c(), i as u32);
+        assert_eq!(Month::from_number(m.number()), Some(m));
+        assert_eq!(Month::from_c(m.to_c()), Some(m));
+    }
+    assert_eq!(Month::from_number(13), None);
+    assert_eq!(Month::December.succ(), Month::January);
+    assert_eq!(Month::January.pred(), Month::December);
+    assert_eq!(Month::November + 3, Month::February);
+    assert_eq!(Month::March - 14, Month::January);
+    assert_eq!(Month::February.days(2024), 29);
+    assert_eq!(Month::February.days(2100), 28);
+}
+
+#[test]
+fn names_round_trip() {
+    assert_eq!(Weekday::Wednesday.short_name(), "Wed");
+    assert_eq!(Weekday::Wednesday.long_name(), "Wednesday");
+    assert_eq!(Month::September.short_name(), "Sep");
+    assert_eq!(Month::September.long_name(), "September");
+
+    assert_eq!("thu".parse::<Weekday>().unwrap(), Weekday::Thursday);
+    assert_eq!("SATURDAY".parse::<Weekday>().unwrap(), Weekday::Saturday);
+    assert_eq!("may".parse::<Month>().unwrap(), Month::May);
+    assert_eq!("December".parse::<Month>().unwrap(), Month::December);
+    assert!("Thurs".parse::<Weekday>().is_err());
+    assert!("Sept".parse::<Month>().is_err());
+}
+
+#[test]
+fn typed_fields() {
+    let rt = ReadableTime::utc_from_timestamp(1_764_485_640).unwrap();
+    assert_eq!(rt.month, Month::November);
+    assert_eq!(rt.week_day, Weekday::Sunday);
+    assert_eq!(ReadableTime::weekstr(1).unwrap(), "Sun");
+    assert_eq!(ReadableTime::monthstr(12).unwrap(), "Dec");
+    assert!(ReadableTime::weekstr(0).is_err());
+}
+
+#[test]
+fn name_cases() {
+    assert_eq!(Weekday::Sunday.long_name_in(Case::Upper), "SUNDAY");
+    assert_eq!(Weekday::Sunday.short_name_in(Case::Lower), "sun");
+    assert_eq!(Month::November.long_name_in(Case::Lower), "november");
+    assert_eq!(Month::November.short_name_in(Case::Upper), "NOV");
+    assert_eq!(Month::May.short_name_in(Case::Title), "May");
+
+    assert_eq!(ReadableTime::weekstr_long(1).unwrap(), "Sunday");
+    assert_eq!(ReadableTime::monthstr_long(11).unwrap(), "November");
+    assert!(ReadableTime::monthstr_long(13).is_err());
+    assert_eq!(ReadableTime::get_time_period(13).unwrap(), "PM");
+    assert_eq!(
+        ReadableTime::get_time_period_in(0, Case::Lower).unwrap(),
+        "am"
+    );
+}
+
+#[test]
+fn long_form() {
+    let rt = ReadableTime::from_timestamp_with_offset(1_764_465_240, 5 * 3600 + 45 * 60).unwrap();
+    assert_eq!(rt.get_long_datef(), "Sunday, November 30, 2025");
+    assert_eq!(
+        rt.get_long_ptimef().unwrap(),
+        "Sunday, November 30, 2025 06:59 AM"
+    );
+}
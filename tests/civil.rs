@@ -0,0 +1,94 @@
+use readable_time::civil::*;
+use readable_time::*;
+
+#[test]
+fn known_dates() {
+    // (year, month, day, days since epoch, weekday 0=Sun, day of year)
+    let table = [
+        (1970, 1, 1, 0, 4, 1),
+        (1969, 12, 31, -1, 3, 365),
+        (2000, 1, 1, 10_957, 6, 1),
+        (2000, 2, 29, 11_016, 2, 60),
+        (2000, 12, 31, 11_322, 0, 366),
+        (1900, 3, 1, -25_508, 4, 60),
+        (1600, 1, 1, -135_140, 6, 1),
+        (1, 1, 1, -719_162, 1, 1),
+        (0, 12, 31, -719_163, 0, 366),
+        (2025, 11, 30, 20_422, 0, 334),
+        (2038, 1, 19, 24_855, 2, 19),
+        (9999, 12, 31, 2_932_896, 5, 365),
+    ];
+    for (y, m, d, days, wd, yd) in table {
+        assert_eq!(days_from_civil(y, m, d), days, "{y}-{m}-{d}");
+        assert_eq!(civil_from_days(days), (y, m, d), "{days}");
+        assert_eq!(weekday_from_days(days), wd, "{y}-{m}-{d}");
+        assert_eq!(day_of_year(y, m, d), yd, "{y}-{m}-{d}");
+    }
+}
+
+#[test]
+fn every_day_from_year_minus_4000_to_8000() {
+    let start = days_from_civil(-4000, 1, 1);
+    let end = days_from_civil(8000, 12, 31);
+    let (mut y, mut m, mut d) = (-4000i64, 1u32, 1u32);
+    let mut yd = 1;
+    let mut wd = weekday_from_days(start);
+
+    for days in start..=end {
+        assert_eq!(civil_from_days(days), (y, m, d));
+        assert_eq!(days_from_civil(y, m, d), days);
+        assert_eq!(weekday_from_days(days), wd);
+        assert_eq!(day_of_year(y, m, d), yd);
+
+        wd = (wd + 1) % 7;
+        yd += 1;
+        d += 1;
+        if d > days_in_month(y, m) {
+            d = 1;
+            m += 1;
+            if m > 12 {
+                assert_eq!(yd - 1, if is_leap_year(y) { 366 } else { 365 });
+                m = 1;
+                y += 1;
+                yd = 1;
+            }
+        }
+    }
+}
+
+#[test]
+fn fixed_offsets() {
+    let rt = ReadableTime::from_timestamp_with_offset(1_764_464_940, 5 * 3600 + 45 * 60).unwrap();
+    assert_eq!(
+        rt.get_extended_ptimef().unwrap(),
+        "Sun Nov 30 06:54:00 +0545 2025"
+    );
+
+    let rt = ReadableTime::from_timestamp_with_offset(0, -5 * 3600).unwrap();
+    assert_eq!(rt.get_timef(), "1969-12-31 19:00:00");
+    assert_eq!(rt.time_zone, "-0500");
+    assert_eq!(rt.hour_12, 7);
+
+    assert!(ReadableTime::from_timestamp_with_offset(0, 86_400).is_err());
+    for offset in [i32::MIN, i32::MAX, -86_400] {
+        assert_eq!(
+            ReadableTime::from_timestamp_with_offset(0, offset).unwrap_err(),
+            ReadableTimeError::InvalidOffset(offset)
+        );
+        let zone = Zone::Fixed(offset);
+        assert_eq!(
+            zone.at(0).unwrap_err(),
+            ReadableTimeError::InvalidOffset(offset)
+        );
+        assert_eq!(
+            zone.offset_at(0).unwrap_err(),
+            ReadableTimeError::InvalidOffset(offset)
+        );
+        assert_eq!(
+            zone.resolve(0).unwrap_err(),
+            ReadableTimeError::InvalidOffset(offset)
+        );
+    }
+    assert!(ReadableTime::from_timestamp_with_offset(0, 86_399).is_ok());
+    assert!(ReadableTime::utc_from_timestamp(time_t::MAX).is_err());
+}
@@ -0,0 +1,63 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
+
+use readable_time::*;
+
+fn rt(s: &str) -> ReadableTime {
+    ReadableTime::parse_rfc3339(s).unwrap()
+}
+
+#[test]
+fn same_instant_is_equal() {
+    let kathmandu = rt("2025-11-30T07:14:00+05:45");
+    let utc = rt("2025-11-30T01:29:00Z");
+    let new_york = rt("2025-11-29T20:29:00-05:00");
+    assert_eq!(kathmandu, utc);
+    assert_eq!(utc, new_york);
+    assert_eq!(kathmandu.cmp(&new_york), Ordering::Equal);
+
+    let set: HashSet<_> = [kathmandu, utc, new_york].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn sorts_by_instant() {
+    let mut events = vec![
+        rt("2025-11-30T09:00:00+05:45"),
+        rt("2025-11-30T01:00:00-05:00"),
+        rt("2025-11-30T02:00:00Z"),
+    ];
+    events.sort();
+    let order: Vec<_> = events.iter().map(|e| e.to_rfc3339().unwrap()).collect();
+    assert_eq!(
+        order,
+        [
+            "2025-11-30T02:00:00Z",
+            "2025-11-30T09:00:00+05:45",
+            "2025-11-30T01:00:00-05:00",
+        ]
+    );
+
+    let mut map = BTreeMap::new();
+    for (i, e) in events.into_iter().enumerate() {
+        map.insert(e, i);
+    }
+    assert_eq!(map.first_key_value().unwrap().1, &0);
+    assert!(rt("2025-11-30T02:00:01Z") > rt("2025-11-30T02:00:00Z"));
+}
+
+#[test]
+fn civil_comparison_ignores_zone() {
+    let kathmandu = rt("2025-11-30T09:00:00+05:45");
+    let new_york = rt("2025-11-30T01:00:00-05:00");
+    assert!(kathmandu < new_york);
+    assert_eq!(kathmandu.cmp_civil(&new_york), Ordering::Greater);
+
+    let a = rt("2025-11-30T07:14:00+05:45");
+    let b = rt("2025-11-30T07:14:00Z");
+    assert!(a.eq_civil(&b));
+    assert_ne!(a, b);
+    assert_eq!(a.cmp_civil(&b), Ordering::Equal);
+}
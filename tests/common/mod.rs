@@ -0,0 +1,15 @@
+// helpers shared by the integration tests, each test uses only some of them
+#![allow(dead_code)]
+
+use readable_time::*;
+
+pub const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/zoneinfo");
+
+/// zone from the checked in zoneinfo fixtures. EXAMPLE: "America/New_York"
+pub fn fixture(name: &str) -> TimeZone {
+    TimeZone::from_file(format!("{FIXTURES}/{name}")).unwrap()
+}
+
+pub fn new_york(timestamp: time_t) -> ReadableTime {
+    ReadableTime::from_timestamp_in(timestamp, &fixture("America/New_York")).unwrap()
+}
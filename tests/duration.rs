@@ -0,0 +1,181 @@
+use readable_time::*;
+use std::time::Duration;
+
+const HOUR: u64 = 3600;
+const DAY: u64 = 86_400;
+
+#[test]
+fn short_style() {
+    let table = [
+        (Duration::from_secs_f64(3723.5), "1h 2m 3s 500ms"),
+        (Duration::from_secs(3723), "1h 2m 3s"),
+        (Duration::from_nanos(1), "1ns"),
+        (Duration::from_nanos(1_001_001), "1ms 1µs 1ns"),
+        (Duration::from_secs(10 * DAY), "1w 3d"),
+        (Duration::from_secs(400 * DAY + HOUR), "1y 5w 1h"),
+        (Duration::ZERO, "0s"),
+    ];
+    for (d, expected) in table {
+        assert_eq!(format_duration(d), expected, "{d:?}");
+    }
+}
+
+#[test]
+fn long_style() {
+    let long = DurationFormat {
+        style: DurationStyle::Long,
+        ..Default::default()
+    };
+    assert_eq!(
+        long.format(Duration::from_secs(3723)),
+        "1 hour, 2 minutes, 3 seconds"
+    );
+    assert_eq!(
+        long.format(Duration::from_secs(2 * DAY + 1)),
+        "2 days, 1 second"
+    );
+    assert_eq!(long.format(Duration::ZERO), "0 seconds");
+}
+
+#[test]
+fn largest_smallest_and_precision() {
+    let minutes = DurationFormat {
+        largest: TimeUnit::Minute,
+        smallest: TimeUnit::Second,
+        ..Default::default()
+    };
+    assert_eq!(minutes.format(Duration::from_secs_f64(5430.9)), "90m 30s");
+
+    let two = DurationFormat {
+        style: DurationStyle::Long,
+        precision: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(two.format(Duration::from_secs(3723)), "1 hour, 2 minutes");
+    assert_eq!(two.format(Duration::from_secs(DAY + 5)), "1 day, 5 seconds");
+
+    // 0 is treated as 1 instead of hiding everything
+    let zero = DurationFormat {
+        precision: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(zero.format(Duration::from_secs(3723)), "1h");
+
+    let hours = DurationFormat {
+        smallest: TimeUnit::Hour,
+        ..Default::default()
+    };
+    assert_eq!(hours.format(Duration::from_secs(59 * 60)), "0h");
+    assert_eq!(
+        hours.format(Duration::from_secs(3 * DAY + 7 * HOUR + 59)),
+        "3d 7h"
+    );
+}
+
+#[test]
+fn clock_style() {
+    let clock = DurationFormat {
+        style: DurationStyle::Clock,
+        smallest: TimeUnit::Second,
+        ..Default::default()
+    };
+    assert_eq!(clock.format(Duration::from_secs(3723)), "01:02:03");
+    assert_eq!(clock.format(Duration::from_secs(100 * HOUR)), "100:00:00");
+
+    let millis = DurationFormat {
+        smallest: TimeUnit::Millisecond,
+        ..clock
+    };
+    assert_eq!(
+        millis.format(Duration::from_secs_f64(3723.5)),
+        "01:02:03.500"
+    );
+
+    let minutes = DurationFormat {
+        largest: TimeUnit::Minute,
+        ..clock
+    };
+    assert_eq!(minutes.format(Duration::from_secs(3723)), "62:03");
+}
+
+#[test]
+fn parse_forms() {
+    let table = [
+        ("1h30m15s", Duration::from_secs(HOUR + 30 * 60 + 15)),
+        ("250ms", Duration::from_millis(250)),
+        ("1.5h", Duration::from_secs(HOUR + 30 * 60)),
+        ("0.000000001s", Duration::from_nanos(1)),
+        ("2 days, 4 hours", Duration::from_secs(2 * DAY + 4 * HOUR)),
+        ("1 hour and 30 minutes", Duration::from_secs(HOUR + 30 * 60)),
+        ("3 Weeks 1 yr", Duration::from_secs(386 * DAY)),
+        ("  10 sec ", Duration::from_secs(10)),
+        ("5µs 3us", Duration::from_micros(8)),
+        ("01:02:03", Duration::from_secs(3723)),
+        ("01:02:03.5", Duration::from_millis(3_723_500)),
+        ("62:03", Duration::from_secs(3723)),
+    ];
+    for (input, expected) in table {
+        let d = parse_duration(input).unwrap_or_else(|e| panic!("{input}: {e}"));
+        assert_eq!(d, expected, "{input}");
+    }
+}
+
+#[test]
+fn parse_errors() {
+    let err = |input: &str| -> ParseError {
+        match parse_duration(input).unwrap_err() {
+            ReadableTimeError::Parse(e) => e,
+            e => panic!("{input}: {e}"),
+        }
+    };
+    let e = err("5 fortnights");
+    assert_eq!(e.position, 2);
+    assert!(e.expected.contains("time unit"), "{e}");
+
+    assert_eq!(err("1h30").position, 4);
+    assert_eq!(err("h").position, 0);
+    assert_eq!(err("").position, 0);
+    assert_eq!(err("1h, ,2m").position, 4);
+    assert_eq!(err("1:60").position, 2);
+    assert_eq!(err("1. h").position, 2);
+
+    assert!(parse_duration("99999999999999999999y").is_err());
+
+    // too big for a 'Duration', like the same amount written with units
+    for input in [
+        "99999999999999999999:00:00",
+        "9999999999999999999:00",
+        "999999999999999999999999999999999999999999:00",
+    ] {
+        assert_eq!(
+            parse_duration(input),
+            Err(ReadableTimeError::DurationTooLarge),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn round_trip() {
+    let long = DurationFormat {
+        style: DurationStyle::Long,
+        ..Default::default()
+    };
+    let clock = DurationFormat {
+        style: DurationStyle::Clock,
+        ..Default::default()
+    };
+    for nanos in [
+        0u64,
+        1,
+        999,
+        1_500_000,
+        3_723_500_000_000,
+        400 * DAY * 1_000_000_000 + 7,
+    ] {
+        let d = Duration::from_nanos(nanos);
+        assert_eq!(parse_duration(&format_duration(d)).unwrap(), d, "{d:?}");
+        assert_eq!(parse_duration(&long.format(d)).unwrap(), d, "{d:?}");
+        assert_eq!(parse_duration(&clock.format(d)).unwrap(), d, "{d:?}");
+    }
+}
@@ -0,0 +1,100 @@
+use readable_time::*;
+
+#[test]
+fn callers_can_match() {
+    assert_eq!(
+        ReadableTime::weekstr(8),
+        Err(ReadableTimeError::InvalidWeekday(8))
+    );
+    for weekday in [i32::MIN, 0, i32::MAX] {
+        assert_eq!(
+            ReadableTime::weekstr(weekday),
+            Err(ReadableTimeError::InvalidWeekday(weekday))
+        );
+        assert_eq!(
+            ReadableTime::weekstr_long(weekday),
+            Err(ReadableTimeError::InvalidWeekday(weekday))
+        );
+    }
+    assert_eq!(
+        ReadableTime::monthstr(0),
+        Err(ReadableTimeError::InvalidMonth(0))
+    );
+    assert_eq!(
+        ReadableTime::get_time_period(24),
+        Err(ReadableTimeError::InvalidHour(24))
+    );
+    assert_eq!(
+        ReadableTime::from_timestamp_with_offset(0, 86_400).unwrap_err(),
+        ReadableTimeError::InvalidOffset(86_400)
+    );
+    assert_eq!(
+        ReadableTime::parse_timef("2025-02-29 00:00:00").unwrap_err(),
+        ReadableTimeError::InvalidDate {
+            year: 2025,
+            month: 2,
+            day: 29
+        }
+    );
+    assert_eq!(
+        ReadableTime::parse("2025 366", "%Y %j").unwrap_err(),
+        ReadableTimeError::InvalidDayOfYear {
+            year: 2025,
+            day: 366
+        }
+    );
+}
+
+#[test]
+fn directive_errors() {
+    let rt = ReadableTime::utc_from_timestamp(0).unwrap();
+    assert_eq!(
+        rt.format("%Y-%Q").unwrap_err(),
+        ReadableTimeError::UnknownDirective {
+            directive: 'Q',
+            position: 3
+        }
+    );
+    assert_eq!(
+        rt.format("abc %").unwrap_err(),
+        ReadableTimeError::IncompleteDirective { position: 4 }
+    );
+    assert_eq!(
+        ReadableTime::parse("x", "x%Q").unwrap_err(),
+        ReadableTimeError::UnknownDirective {
+            directive: 'Q',
+            position: 1
+        }
+    );
+}
+
+#[test]
+fn zone_errors() {
+    assert_eq!(
+        TimeZone::named("../etc/passwd").unwrap_err(),
+        ReadableTimeError::InvalidTimeZoneName
+    );
+    assert!(matches!(
+        TimeZone::from_tzif("x", b"nope").unwrap_err(),
+        ReadableTimeError::InvalidTzif(_)
+    ));
+    assert!(matches!(
+        TimeZone::from_file("/nonexistent/zone").unwrap_err(),
+        ReadableTimeError::Io(std::io::ErrorKind::NotFound)
+    ));
+    let ReadableTimeError::InvalidTzString(e) = PosixTz::parse("EST5EDT,M3.2").unwrap_err() else {
+        panic!("expected a TZ string error");
+    };
+    assert_eq!(e.position, 12);
+}
+
+#[test]
+fn copy_and_display() {
+    let e = ReadableTimeError::InvalidMonth(13);
+    let copy = e;
+    assert_eq!(e, copy);
+    assert_eq!(e.to_string(), "invalid month 13. month should be 1-12");
+
+    let boxed: Box<dyn std::error::Error> = Box::new(ReadableTimeError::ClockBeforeEpoch);
+    assert_eq!(boxed.to_string(), "system clock is set before 1970");
+}
@@ -0,0 +1,76 @@
+use readable_time::*;
+
+// Sun Nov 30 2025 06:54:09 +0545
+fn sample() -> ReadableTime {
+    ReadableTime::from_timestamp_with_offset(1_764_464_949, 5 * 3600 + 45 * 60).unwrap()
+}
+
+#[test]
+fn directives() {
+    let rt = sample();
+    let table = [
+        ("%Y-%m-%d %H:%M:%S", "2025-11-30 06:54:09"),
+        ("%y %C", "25 20"),
+        ("%I %p", "06 AM"),
+        ("%a %A %b %B %h", "Sun Sunday Nov November Nov"),
+        ("%u %w %j", "7 0 334"),
+        ("%U %V %G", "48 48 2025"),
+        ("%z %:z %Z", "+0545 +05:45 +0545"),
+        ("%s", "1764464949"),
+        ("%F %T %R", "2025-11-30 06:54:09 06:54"),
+        ("100%% %n%t", "100% \n\t"),
+        ("plain text", "plain text"),
+    ];
+    for (fmt, expected) in table {
+        assert_eq!(rt.format(fmt).unwrap(), expected, "{fmt}");
+    }
+}
+
+#[test]
+fn padding_modifiers() {
+    // Mon Jan 5 2026 03:04:05 UTC
+    let rt = ReadableTime::utc_from_timestamp(1_767_582_245).unwrap();
+    assert_eq!(
+        rt.format("%d %-d %_d %e %0e %-e").unwrap(),
+        "05 5  5  5 05 5"
+    );
+    assert_eq!(rt.format("%-H:%M %-I %-j %_j").unwrap(), "3:04 3 5   5");
+    // 2026-01-05 is in ISO week 2 of 2026
+    assert_eq!(rt.format("%V %-V %U").unwrap(), "02 2 01");
+}
+
+#[test]
+fn iso_week_year_boundary() {
+    // Sat Jan 1 2022 is in week 52 of 2021, Mon Dec 29 2025 in week 1 of 2026
+    let rt = ReadableTime::utc_from_timestamp(1_640_995_200).unwrap();
+    assert_eq!(rt.format("%G-W%V-%u").unwrap(), "2021-W52-6");
+    let rt = ReadableTime::utc_from_timestamp(1_766_966_400).unwrap();
+    assert_eq!(rt.format("%G-W%V-%u").unwrap(), "2026-W01-1");
+    // Thu Dec 31 2020 is in week 53
+    let rt = ReadableTime::utc_from_timestamp(1_609_372_800).unwrap();
+    assert_eq!(rt.format("%G-W%V-%u").unwrap(), "2020-W53-4");
+}
+
+#[test]
+fn fixed_layouts() {
+    let rt = sample();
+    assert_eq!(rt.get_timef(), "2025-11-30 06:54:09");
+    assert_eq!(rt.get_ptimef().unwrap(), "Sun Nov 30 2025 06:54 AM");
+    assert_eq!(
+        rt.get_extended_ptimef().unwrap(),
+        "Sun Nov 30 06:54:09 +0545 2025"
+    );
+}
+
+#[test]
+fn errors() {
+    let rt = sample();
+    let err = rt.format("%Y-%Q").unwrap_err().to_string();
+    assert!(err.contains("'%Q'") && err.contains("position 3"), "{err}");
+    assert!(rt.format("trailing %").is_err());
+    assert!(rt.format("%:Y").is_err());
+
+    let mut bad = sample();
+    bad.hour_24 = 24;
+    assert!(bad.format("%p").is_err());
+}
@@ -0,0 +1,161 @@
+use readable_time::*;
+
+/// Sun 2025-11-30 07:14:09 +0545
+fn sample() -> ReadableTime {
+    ReadableTime::from_timestamp_with_offset(1_764_466_149, 5 * 3600 + 45 * 60).unwrap()
+}
+
+#[test]
+fn built_in_long_dates() {
+    let rt = sample();
+    let table = [
+        (Locale::EN_US, "Sunday, November 30, 2025"),
+        (Locale::NE_NP, "2025 नोभेम्बर 30, आइतबार"),
+        (Locale::DE_DE, "Sonntag, 30. November 2025"),
+        (Locale::FR_FR, "dimanche 30 novembre 2025"),
+        (Locale::ES_ES, "domingo, 30 de noviembre de 2025"),
+        (Locale::JA_JP, "2025年11月30日 日曜日"),
+    ];
+    for (locale, expected) in table {
+        assert_eq!(
+            rt.get_long_datef_localized(&locale).unwrap(),
+            expected,
+            "{}",
+            locale.name
+        );
+    }
+    assert_eq!(
+        rt.get_long_datef(),
+        rt.get_long_datef_localized(&Locale::EN_US).unwrap()
+    );
+}
+
+#[test]
+fn locale_layouts() {
+    let rt = sample();
+    assert_eq!(rt.format("%x %X").unwrap(), "11/30/2025 07:14:09 AM");
+    assert_eq!(rt.format("%c").unwrap(), "Sun Nov 30 07:14:09 2025");
+    assert_eq!(
+        rt.format_localized("%x %X", &Locale::DE_DE).unwrap(),
+        "30.11.2025 07:14:09"
+    );
+    assert_eq!(
+        rt.format_localized("%c", &Locale::JA_JP).unwrap(),
+        "2025年11月30日 07時14分09秒"
+    );
+    assert_eq!(
+        rt.format_localized("%a %b %p", &Locale::FR_FR).unwrap(),
+        "dim. nov. AM"
+    );
+    assert_eq!(
+        rt.get_ptimef_localized(&Locale::ES_ES).unwrap(),
+        "dom nov 30 2025 07:14 a. m."
+    );
+    assert_eq!(
+        rt.get_long_ptimef_localized(&Locale::JA_JP).unwrap(),
+        "2025年11月30日 日曜日 07:14 午前"
+    );
+    assert_eq!(
+        rt.get_extended_ptimef_localized(&Locale::NE_NP).unwrap(),
+        "आइत नोभे 30 07:14:09 +0545 2025"
+    );
+}
+
+#[test]
+fn name_lookups() {
+    let de = Locale::DE_DE;
+    assert_eq!(de.weekday(Weekday::Thursday), "Donnerstag");
+    assert_eq!(de.weekday_short(Weekday::Thursday), "Do");
+    assert_eq!(de.month(Month::March), "März");
+    assert_eq!(de.month_short(Month::March), "Mär");
+    assert_eq!(Locale::NE_NP.period(13).unwrap(), "अपराह्न");
+    assert!(de.period(24).is_err());
+    assert_eq!(de.language(), "de");
+}
+
+#[test]
+fn get_by_name() {
+    assert_eq!(Locale::get("de_DE"), Some(Locale::DE_DE));
+    assert_eq!(Locale::get("ja-JP"), Some(Locale::JA_JP));
+    assert_eq!(Locale::get("fr_FR.UTF-8"), Some(Locale::FR_FR));
+    assert_eq!(Locale::get("ES"), Some(Locale::ES_ES));
+    assert_eq!(Locale::get("ne"), Some(Locale::NE_NP));
+    assert_eq!(Locale::get("xx_XX"), None);
+    assert_eq!(Locale::default(), Locale::EN_US);
+}
+
+#[test]
+fn register_custom() {
+    let nl = Locale {
+        name: "nl_NL",
+        weekdays: [
+            "zondag",
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+        ],
+        weekdays_short: ["zo", "ma", "di", "wo", "do", "vr", "za"],
+        months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        months_short: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        date_format: "%d-%m-%Y",
+        long_date_format: "%A %-d %B %Y",
+        ..Locale::DE_DE
+    };
+    Locale::register(nl);
+    let found = Locale::get("nl").unwrap();
+    assert_eq!(found, nl);
+    assert_eq!(
+        sample().get_long_datef_localized(&found).unwrap(),
+        "zondag 30 november 2025"
+    );
+
+    // registered locales are searched first, so they win a language only lookup.
+    // the exact built in name still finds the built in locale
+    Locale::register(Locale {
+        name: "de_AT",
+        months: {
+            let mut m = Locale::DE_DE.months;
+            m[0] = "Jänner";
+            m
+        },
+        ..Locale::DE_DE
+    });
+    assert_eq!(Locale::get("de_AT").unwrap().months[0], "Jänner");
+    assert_eq!(Locale::get("de").unwrap().name, "de_AT");
+    assert_eq!(Locale::get("de_DE"), Some(Locale::DE_DE));
+}
+
+#[test]
+fn bad_layout_is_an_error() {
+    let broken = Locale {
+        name: "xx_BROKEN",
+        long_date_format: "%A %Q",
+        ..Locale::EN_US
+    };
+    assert_eq!(
+        sample().get_long_datef_localized(&broken).unwrap_err(),
+        ReadableTimeError::UnknownDirective {
+            directive: 'Q',
+            position: 3
+        }
+    );
+    assert!(sample().get_long_ptimef_localized(&broken).is_err());
+}
@@ -0,0 +1,78 @@
+use readable_time::*;
+
+#[test]
+fn round_trip_timef() {
+    let rt = ReadableTime::utc_from_timestamp(1_764_464_949).unwrap();
+    let back = ReadableTime::parse_timef(&rt.get_timef()).unwrap();
+    assert_eq!(back.get_timef(), rt.get_timef());
+    assert_eq!(back.week_day, rt.week_day);
+    assert_eq!(back.day_of_year, rt.day_of_year);
+    assert_eq!(back.time_zone, "UTC");
+}
+
+#[test]
+fn round_trip_extended() {
+    let rt = ReadableTime::from_timestamp_with_offset(1_764_464_949, 5 * 3600 + 45 * 60).unwrap();
+    let text = rt.get_extended_ptimef().unwrap();
+    let back = ReadableTime::parse(&text, "%a %b %d %H:%M:%S %z %Y").unwrap();
+    assert_eq!(back.get_extended_ptimef().unwrap(), text);
+    assert_eq!(back.utc_offset_seconds, rt.utc_offset_seconds);
+    assert_eq!(back.format("%s").unwrap(), "1764464949");
+}
+
+#[test]
+fn directives() {
+    let rt =
+        ReadableTime::parse("Sunday, 30 november 25 7:14 pm", "%A, %d %B %y %I:%M %p").unwrap();
+    assert_eq!(rt.get_timef(), "2025-11-30 19:14:00");
+
+    let rt = ReadableTime::parse("20251130T071400", "%Y%m%dT%H%M%S").unwrap();
+    assert_eq!(rt.get_timef(), "2025-11-30 07:14:00");
+
+    let rt = ReadableTime::parse("2024 060", "%Y %j").unwrap();
+    assert_eq!(rt.get_timef(), "2024-02-29 00:00:00");
+
+    let rt = ReadableTime::parse("12:30 AM", "%I:%M %p").unwrap();
+    assert_eq!(rt.get_timef(), "1970-01-01 00:30:00");
+
+    let rt = ReadableTime::parse("1764464949 +05:45", "%s %:z").unwrap();
+    assert_eq!(rt.get_timef(), "2025-11-30 06:54:09");
+
+    let rt = ReadableTime::parse("2025-11-30   07:14 GMT", "%F %R %Z").unwrap();
+    assert_eq!(rt.time_zone, "GMT");
+    assert_eq!(rt.utc_offset_seconds, 0);
+
+    let rt = ReadableTime::parse("100% 5", "100%% %e").unwrap();
+    assert_eq!(rt.day, 5);
+}
+
+#[test]
+fn error_positions() {
+    let err = |input: &str, fmt: &str| -> ParseError {
+        match ReadableTime::parse(input, fmt).unwrap_err() {
+            ReadableTimeError::Parse(e) => e,
+            e => panic!("{input}: {e}"),
+        }
+    };
+
+    let e = err("2025-13-01 00:00:00", "%Y-%m-%d %H:%M:%S");
+    assert_eq!((e.position, e.expected), (5, "month 01-12"));
+
+    let e = err("2025-11-30 07-14-00", "%Y-%m-%d %H:%M:%S");
+    assert_eq!((e.position, e.expected), (13, "':'"));
+
+    let e = err("2025-11-30 extra", "%Y-%m-%d");
+    assert_eq!((e.position, e.expected), (10, "end of input"));
+
+    let e = err("Mon 2025-11-30", "%a %F");
+    assert_eq!(e.position, 0);
+    assert!(e.to_string().contains("byte 0"));
+}
+
+#[test]
+fn invalid_dates() {
+    assert!(ReadableTime::parse_timef("2025-02-29 00:00:00").is_err());
+    assert!(ReadableTime::parse_timef("2025-01-01 24:00:00").is_err());
+    assert!(ReadableTime::parse("2025 366", "%Y %j").is_err());
+    assert!(ReadableTime::parse("x", "%Q").is_err());
+}
@@ -0,0 +1,144 @@
+use readable_time::posix_tz::{Rule, RuleDay};
+use readable_time::*;
+
+mod common;
+use common::fixture;
+
+#[test]
+fn parse_us_eastern() {
+    let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    assert_eq!(tz.std_abbreviation, "EST");
+    assert_eq!(tz.std_offset, -5 * 3600);
+    let dst = tz.dst.unwrap();
+    assert_eq!(dst.abbreviation, "EDT");
+    assert_eq!(dst.utc_offset, -4 * 3600);
+    assert_eq!(
+        dst.start,
+        Rule {
+            day: RuleDay::MonthWeekDay {
+                month: 3,
+                week: 2,
+                day: 0
+            },
+            time: 7200
+        }
+    );
+}
+
+#[test]
+fn parse_variants() {
+    let tz: PosixTz = "<+0545>-5:45".parse().unwrap();
+    assert_eq!(tz.std_abbreviation, "+0545");
+    assert_eq!(tz.std_offset, 5 * 3600 + 45 * 60);
+    assert!(tz.dst.is_none());
+
+    let tz = PosixTz::parse("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1").unwrap();
+    assert_eq!(tz.dst.as_ref().unwrap().start.time, -7200);
+
+    let tz = PosixTz::parse("XXX3YYY,J60/25,300").unwrap();
+    let dst = tz.dst.unwrap();
+    assert_eq!(dst.start.day, RuleDay::Julian1(60));
+    assert_eq!(dst.start.time, 25 * 3600);
+    assert_eq!(dst.end.day, RuleDay::Julian0(300));
+    assert_eq!(dst.utc_offset, -2 * 3600);
+
+    for bad in [
+        "",
+        "E5",
+        "EST",
+        "EST5EDT,M13.1.0,M11.1.0",
+        "EST5EDT,M3.2.0",
+        "EST5x",
+        "<+05",
+    ] {
+        assert!(PosixTz::parse(bad).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn northern_transitions() {
+    let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    // 2024-03-10 07:00 UTC and 2024-11-03 06:00 UTC
+    assert!(!tz.local_time_type(1_710_053_999).is_dst);
+    assert!(tz.local_time_type(1_710_054_000).is_dst);
+    assert!(tz.local_time_type(1_730_613_599).is_dst);
+    let after = tz.local_time_type(1_730_613_600);
+    assert!(!after.is_dst);
+    assert_eq!(after.abbreviation, "EST");
+}
+
+#[test]
+fn southern_hemisphere() {
+    let tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    let jan = tz.local_time_type(1_705_320_000);
+    assert!(jan.is_dst);
+    assert_eq!(jan.utc_offset, 11 * 3600);
+    assert!(!tz.local_time_type(1_720_094_400).is_dst);
+    // 2024-04-07 03:00 AEDT = 2024-04-06 16:00 UTC
+    assert!(tz.local_time_type(1_712_419_199).is_dst);
+    assert!(!tz.local_time_type(1_712_419_200).is_dst);
+}
+
+#[test]
+fn footer_matches_tzif_transitions() {
+    let tz = fixture("America/New_York");
+    let rule = tz.rule().unwrap();
+    let since_2007 = tz.transitions().iter().filter(|t| t.time >= 1_167_609_600);
+    let mut checked = 0;
+    for t in since_2007 {
+        assert_eq!(rule.local_time_type(t.time), tz.local_types()[t.local_type]);
+        checked += 1;
+    }
+    assert!(checked > 50);
+}
+
+#[test]
+fn footer_used_after_last_transition() {
+    let tz = fixture("America/New_York");
+    // 2050-07-01 12:00 UTC and 2050-01-01 12:00 UTC
+    let summer = ReadableTime::from_timestamp_in(2_540_289_600, &tz).unwrap();
+    assert_eq!(summer.time_zone, "EDT");
+    assert_eq!(summer.get_timef(), "2050-07-01 08:00:00");
+    let winter = ReadableTime::from_timestamp_in(2_524_651_200, &tz).unwrap();
+    assert_eq!(winter.time_zone, "EST");
+}
+
+#[test]
+fn zone_from_posix_string() {
+    let tz = TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    let rt = ReadableTime::from_timestamp_in(1_720_094_400, &tz).unwrap();
+    assert_eq!(rt.get_timef(), "2024-07-04 14:00:00");
+    assert_eq!(rt.time_zone, "CEST");
+
+    let tz = TimeZone::from_tz("<+0545>-5:45").unwrap();
+    let rt = ReadableTime::from_timestamp_in(0, &tz).unwrap();
+    assert_eq!(rt.get_timef(), "1970-01-01 05:45:00");
+}
+
+#[test]
+fn extreme_timestamps() {
+    for rule in [
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+        "EST5EDT,M3.2.0,M11.1.0",
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ] {
+        let posix = PosixTz::parse(rule).unwrap();
+        for t in [i64::MAX, i64::MIN, i64::MAX - 3600, i64::MIN + 3600] {
+            // answers without overflowing
+            posix.local_time_type(t);
+        }
+        let tz = TimeZone::from_posix(rule).unwrap();
+        for t in [time_t::MAX, time_t::MIN] {
+            assert_eq!(
+                ReadableTime::from_timestamp_in(t, &tz).unwrap_err(),
+                ReadableTimeError::OutOfRange,
+                "{rule} {t}"
+            );
+        }
+    }
+    // the New York fixture falls back to its footer rule after the last transition
+    let tz = fixture("America/New_York");
+    for t in [time_t::MAX, time_t::MIN] {
+        assert!(ReadableTime::from_timestamp_in(t, &tz).is_err());
+    }
+}
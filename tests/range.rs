@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use readable_time::*;
+
+mod common;
+use common::new_york;
+
+fn date(year: i32, month: Month, day: i32) -> Date {
+    Date::new(year, month, day).unwrap()
+}
+
+fn strings<T: ToString>(items: impl Iterator<Item = T>) -> Vec<String> {
+    items.map(|i| i.to_string()).collect()
+}
+
+#[test]
+fn date_type() {
+    let d = date(2025, Month::November, 30);
+    assert_eq!(d.to_string(), "2025-11-30");
+    assert_eq!(d.week_day(), Weekday::Sunday);
+    assert_eq!(d.day_of_year(), 334);
+    assert_eq!(Date::from_days(d.to_days()).unwrap(), d);
+    assert_eq!(d.add_days(1).unwrap(), date(2025, Month::December, 1));
+    assert_eq!(
+        date(2024, Month::January, 31).add_months(1).unwrap(),
+        date(2024, Month::February, 29)
+    );
+    assert!(date(2025, Month::January, 1) < d);
+    assert_eq!(
+        Date::new(2025, Month::February, 29).unwrap_err(),
+        ReadableTimeError::InvalidDate {
+            year: 2025,
+            month: 2,
+            day: 29
+        }
+    );
+    assert!(Date::new(2025, Month::March, 0).is_err());
+
+    let rt = ReadableTime::utc_from_timestamp(1_764_464_940).unwrap();
+    assert_eq!(rt.date(), date(2025, Month::November, 30));
+    assert_eq!(
+        d.start_of_day(&Zone::Utc).unwrap().get_timef(),
+        "2025-11-30 00:00:00"
+    );
+}
+
+#[test]
+fn date_limits() {
+    for days in [
+        i64::MAX,
+        i64::MIN,
+        Date::MAX.to_days() + 1,
+        Date::MIN.to_days() - 1,
+    ] {
+        assert_eq!(
+            Date::from_days(days).unwrap_err(),
+            ReadableTimeError::OutOfRange,
+            "{days}"
+        );
+    }
+    assert_eq!(Date::from_days(Date::MAX.to_days()).unwrap(), Date::MAX);
+    assert_eq!(Date::from_days(Date::MIN.to_days()).unwrap(), Date::MIN);
+    assert_eq!(Date::MIN.to_string(), "-2147483648-01-01");
+
+    let d = date(2025, Month::November, 30);
+    for n in [i64::MAX, i64::MIN, i64::MAX / 24, i64::MIN / 24] {
+        assert_eq!(d.add_days(n).unwrap_err(), ReadableTimeError::OutOfRange);
+        assert_eq!(d.add_months(n).unwrap_err(), ReadableTimeError::OutOfRange);
+    }
+    assert_eq!(
+        Date::MAX.add_days(1).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    assert_eq!(
+        Date::MIN.add_months(-1).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    assert_eq!(
+        date(i32::MAX, Month::December, 1)
+            .add_months(1)
+            .unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    assert_eq!(
+        Date::MAX.start_of_day(&Zone::Utc).unwrap().date(),
+        Date::MAX
+    );
+    assert_eq!(
+        date(-1, Month::December, 31).add_days(1).unwrap(),
+        date(0, Month::January, 1)
+    );
+
+    // ranges stop at the limit instead of failing
+    let start = date(i32::MAX, Month::December, 25);
+    assert_eq!(DateRange::new(start, Date::MAX).inclusive().count(), 7);
+    assert_eq!(
+        DateRange::new(start, Date::MAX)
+            .inclusive()
+            .step(Step::Weeks(1))
+            .rev()
+            .collect::<Vec<_>>(),
+        [start]
+    );
+    assert_eq!(
+        DateRange::new(Date::MIN, Date::MAX)
+            .step(Step::Months(u32::MAX))
+            .count(),
+        // about 358 million years per step
+        13
+    );
+}
+
+#[test]
+fn days_inclusive_and_exclusive() {
+    let start = date(2025, Month::February, 26);
+    let end = date(2025, Month::March, 2);
+    assert_eq!(
+        strings(DateRange::new(start, end)),
+        ["2025-02-26", "2025-02-27", "2025-02-28", "2025-03-01"]
+    );
+    assert_eq!(DateRange::new(start, end).inclusive().count(), 5);
+    assert_eq!(DateRange::new(end, start).count(), 0);
+    assert_eq!(DateRange::new(start, start).count(), 0);
+    assert_eq!(DateRange::new(start, start).inclusive().count(), 1);
+}
+
+#[test]
+fn weeks_months_and_exact() {
+    let start = date(2025, Month::January, 31);
+    let end = date(2025, Month::June, 1);
+    assert_eq!(
+        strings(DateRange::new(start, end).step(Step::Months(1))),
+        [
+            "2025-01-31",
+            "2025-02-28",
+            "2025-03-31",
+            "2025-04-30",
+            "2025-05-31"
+        ]
+    );
+    assert_eq!(
+        strings(DateRange::new(start, end).step(Step::Months(2))),
+        ["2025-01-31", "2025-03-31", "2025-05-31"]
+    );
+    assert_eq!(
+        strings(
+            DateRange::new(start, date(2025, Month::February, 21))
+                .inclusive()
+                .step(Step::Weeks(1))
+        ),
+        ["2025-01-31", "2025-02-07", "2025-02-14", "2025-02-21"]
+    );
+    // exact steps are rounded up to whole days
+    assert_eq!(
+        DateRange::new(start, end)
+            .step(Step::Exact(Duration::from_secs(3 * 86_400)))
+            .nth(1),
+        Some(date(2025, Month::February, 3))
+    );
+    assert_eq!(
+        strings(
+            DateRange::new(start, date(2025, Month::February, 6))
+                .step(Step::Exact(Duration::from_secs(36 * 3600)))
+        ),
+        ["2025-01-31", "2025-02-02", "2025-02-04"]
+    );
+    assert_eq!(
+        DateRange::new(start, end)
+            .step(Step::Exact(Duration::from_secs(3600)))
+            .count(),
+        DateRange::new(start, end).count()
+    );
+
+    // zero steps never end, so they are empty instead
+    assert_eq!(DateRange::new(start, end).step(Step::Days(0)).count(), 0);
+    assert_eq!(
+        DateRange::new(start, end)
+            .step(Step::Exact(Duration::ZERO))
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn reversed() {
+    let start = date(2025, Month::January, 31);
+    let end = date(2025, Month::May, 31);
+    let forward = strings(DateRange::new(start, end).inclusive().step(Step::Months(1)));
+    let mut backward = strings(
+        DateRange::new(start, end)
+            .inclusive()
+            .step(Step::Months(1))
+            .rev(),
+    );
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    // both ends meet in the middle
+    let mut range = DateRange::new(date(2025, Month::March, 1), date(2025, Month::March, 5));
+    assert_eq!(range.size_hint(), (4, Some(4)));
+    assert_eq!(range.next(), Some(date(2025, Month::March, 1)));
+    assert_eq!(range.next_back(), Some(date(2025, Month::March, 4)));
+    assert_eq!(range.size_hint(), (2, Some(2)));
+    assert_eq!(range.next_back(), Some(date(2025, Month::March, 3)));
+    assert_eq!(range.next(), Some(date(2025, Month::March, 2)));
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+    assert_eq!(range.size_hint(), (0, Some(0)));
+
+    // known before the first item, 'collect' can allocate once
+    let months = DateRange::new(
+        date(2025, Month::January, 31),
+        date(2026, Month::January, 1),
+    )
+    .step(Step::Months(1));
+    assert_eq!(months.size_hint(), (12, Some(12)));
+}
+
+#[test]
+fn times_across_dst() {
+    // 2025-03-08 09:00 EST, DST starts the next night
+    let start = new_york(1_741_442_400);
+    let end = start.add_days(3).unwrap();
+    let days: Vec<String> = TimeRange::new(start.clone(), end)
+        .map(|rt| rt.format("%F %H:%M %Z").unwrap())
+        .collect();
+    assert_eq!(
+        days,
+        [
+            "2025-03-08 09:00 EST",
+            "2025-03-09 09:00 EDT",
+            "2025-03-10 09:00 EDT"
+        ]
+    );
+
+    // 2025-03-09 00:00 EST, 02:00 does not exist that night
+    let midnight = new_york(1_741_496_400);
+    let end = midnight
+        .add_duration(Duration::from_secs(4 * 3600))
+        .unwrap();
+    let hours: Vec<String> = TimeRange::new(midnight, end.clone())
+        .step(Step::Exact(Duration::from_secs(3600)))
+        .map(|rt| rt.format("%H:%M %Z").unwrap())
+        .collect();
+    assert_eq!(hours, ["00:00 EST", "01:00 EST", "03:00 EDT", "04:00 EDT"]);
+
+    // end is compared as an instant, whatever its zone
+    let hours = TimeRange::new(new_york(1_741_496_400), end.to_utc().unwrap())
+        .inclusive()
+        .step(Step::Exact(Duration::from_secs(3600)))
+        .rev()
+        .map(|rt| rt.format("%H:%M").unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(hours, ["05:00", "04:00", "03:00", "01:00", "00:00"]);
+}
+
+#[test]
+fn time_months_keep_wall_clock() {
+    let start = ReadableTime::parse_rfc3339("2025-01-31T12:30:00Z").unwrap();
+    let end = ReadableTime::parse_rfc3339("2025-04-30T12:30:00Z").unwrap();
+    let months: Vec<String> = TimeRange::new(start, end)
+        .inclusive()
+        .step(Step::Months(1))
+        .map(|rt| rt.get_timef())
+        .collect();
+    assert_eq!(
+        months,
+        [
+            "2025-01-31 12:30:00",
+            "2025-02-28 12:30:00",
+            "2025-03-31 12:30:00",
+            "2025-04-30 12:30:00"
+        ]
+    );
+}
@@ -0,0 +1,92 @@
+use readable_time::*;
+
+/// fixed reference clock: Sun Nov 30 2025 06:54:00 UTC
+const NOW: time_t = 1_764_485_640;
+
+fn at(offset: time_t) -> ReadableTime {
+    ReadableTime::utc_from_timestamp(NOW + offset).unwrap()
+}
+
+fn rel(offset: time_t) -> String {
+    at(offset).relative_to(&at(0))
+}
+
+#[test]
+fn past_and_future() {
+    let table = [
+        (0, "just now"),
+        (-9, "just now"),
+        (9, "just now"),
+        (-30, "30 seconds ago"),
+        (-45, "1 minute ago"),
+        (-5 * 60, "5 minutes ago"),
+        (5 * 60, "in 5 minutes"),
+        (-44 * 60, "44 minutes ago"),
+        (-45 * 60, "1 hour ago"),
+        (-3 * 3600, "3 hours ago"),
+        (-22 * 3600, "yesterday"),
+        (30 * 3600, "tomorrow"),
+        (-3 * 86_400, "3 days ago"),
+        (-7 * 86_400, "1 week ago"),
+        (21 * 86_400, "in 3 weeks"),
+        (-40 * 86_400, "1 month ago"),
+        (-200 * 86_400, "7 months ago"),
+        (-330 * 86_400, "1 year ago"),
+        (3 * 365 * 86_400, "in 3 years"),
+    ];
+    for (offset, expected) in table {
+        assert_eq!(rel(offset), expected, "offset {offset}");
+    }
+}
+
+#[test]
+fn yesterday_is_the_calendar_day_before() {
+    // same day, 22 hours apart
+    let morning = ReadableTime::utc_from_timestamp(1_764_466_200).unwrap(); // 01:30
+    let night = ReadableTime::utc_from_timestamp(1_764_545_400).unwrap(); // 23:30
+    assert_eq!(morning.relative_to(&night), "1 day ago");
+    assert_eq!(night.relative_to(&morning), "in 1 day");
+
+    // 47 hours back is still the previous calendar day
+    let early = ReadableTime::utc_from_timestamp(1_764_376_200).unwrap(); // 29th 00:30
+    assert_eq!(early.relative_to(&night), "yesterday");
+
+    // the calendar day is taken in the zone of the reference time
+    let kathmandu = ReadableTime::from_timestamp_with_offset(1_764_545_400, 20_700).unwrap();
+    assert_eq!(morning.relative_to(&kathmandu), "yesterday");
+}
+
+#[test]
+fn zones_do_not_matter() {
+    let kathmandu = ReadableTime::from_timestamp_with_offset(NOW - 120, 20_700).unwrap();
+    assert_eq!(kathmandu.relative_to(&at(0)), "2 minutes ago");
+}
+
+#[test]
+fn custom_thresholds_and_rounding() {
+    let opts = RelativeOptions {
+        just_now: 0,
+        minutes: 120,
+        rounding: Rounding::Floor,
+        ..Default::default()
+    };
+    let humanize = |offset| at(offset).relative_to_with(&at(0), &opts);
+    assert_eq!(humanize(-3), "3 seconds ago");
+    assert_eq!(humanize(-90 * 60), "90 minutes ago");
+    assert_eq!(humanize(-(2 * 3600 + 59 * 60)), "2 hours ago");
+
+    let ceil = RelativeOptions {
+        rounding: Rounding::Ceil,
+        ..Default::default()
+    };
+    assert_eq!(
+        at(-(3600 + 60)).relative_to_with(&at(0), &ceil),
+        "2 hours ago"
+    );
+}
+
+#[test]
+fn since_now() {
+    let rt = get_readable_time_utc().unwrap();
+    assert_eq!(rt.humanize_since_now().unwrap(), "just now");
+}
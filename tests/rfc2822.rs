@@ -0,0 +1,94 @@
+use readable_time::*;
+
+fn ts(rt: &ReadableTime) -> String {
+    rt.format("%s").unwrap()
+}
+
+#[test]
+fn formatting() {
+    let rt = ReadableTime::from_timestamp_with_offset(784_111_777, -5 * 3600).unwrap();
+    assert_eq!(rt.to_rfc2822().unwrap(), "Sun, 6 Nov 1994 03:49:37 -0500");
+    assert_eq!(rt.to_http_date().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    assert_eq!(rt.to_rfc850().unwrap(), "Sunday, 06-Nov-94 08:49:37 GMT");
+    assert_eq!(
+        rt.to_utc().unwrap().to_asctime().unwrap(),
+        "Sun Nov  6 08:49:37 1994"
+    );
+}
+
+#[test]
+fn http_date_forms() {
+    for input in [
+        "Sun, 06 Nov 1994 08:49:37 GMT",
+        "Sunday, 06-Nov-94 08:49:37 GMT",
+        "Sun Nov  6 08:49:37 1994",
+    ] {
+        let rt = ReadableTime::parse_http_date(input).unwrap_or_else(|e| panic!("{input}: {e}"));
+        assert_eq!(ts(&rt), "784111777", "{input}");
+        assert_eq!(rt.time_zone, "GMT");
+    }
+    assert!(ReadableTime::parse_http_date("Sun, 06 Nov 1994 08:49:37 EST").is_err());
+    assert!(ReadableTime::parse_http_date("Sun, 6 Nov 1994 08:49:37 GMT").is_err());
+    assert!(ReadableTime::parse_http_date("Mon, 06 Nov 1994 08:49:37 GMT").is_err());
+}
+
+#[test]
+fn rfc2822_lenient() {
+    let table = [
+        ("Sun, 06 Nov 1994 08:49:37 GMT", "784111777", 0),
+        ("Sun, 6 Nov 1994 03:49:37 -0500", "784111777", -5 * 3600),
+        ("6 Nov 1994 03:49:37 EST", "784111777", -5 * 3600),
+        (
+            "Sun,  6 Nov 94 00:49:37 PST (Pacific)",
+            "784111777",
+            -8 * 3600,
+        ),
+        ("Sun, 06 Nov 1994 08:49 UT", "784111740", 0),
+        ("sun, 06 nov 1994 08:49:37 z", "784111777", 0),
+        ("Sun, 06 Nov 1994 08:49:37 A", "784111777", 0),
+        (
+            "Sun, 30 Nov 2025 07:14:00 +0545",
+            "1764466140",
+            5 * 3600 + 45 * 60,
+        ),
+        ("Mon, 1 Jan 01 00:00:00 +0000", "978307200", 0),
+    ];
+    for (input, expected, offset) in table {
+        let rt = ReadableTime::parse_rfc2822(input).unwrap_or_else(|e| panic!("{input}: {e}"));
+        assert_eq!(ts(&rt), expected, "{input}");
+        assert_eq!(rt.utc_offset_seconds, offset, "{input}");
+    }
+}
+
+#[test]
+fn rfc2822_errors() {
+    for input in [
+        "Mon, 06 Nov 1994 08:49:37 GMT",
+        "Sun 06 Nov 1994 08:49:37 GMT",
+        "Sun, 06 Nox 1994 08:49:37 GMT",
+        "Sun, 06 Nov 1994 08:49:37 XYZ",
+        "Sun, 06 Nov 1994 08:49:37",
+        "Sun, 06 Nov 1994 08:49:37 GMT junk",
+    ] {
+        assert!(ReadableTime::parse_rfc2822(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn asctime_accepts_extended_ptimef() {
+    let rt = ReadableTime::from_timestamp_with_offset(1_764_465_540, 5 * 3600 + 45 * 60).unwrap();
+    let text = rt.get_extended_ptimef().unwrap();
+    let back = ReadableTime::parse_asctime(&text).unwrap();
+    assert_eq!(ts(&back), "1764465540");
+    assert_eq!(back.get_extended_ptimef().unwrap(), text);
+
+    let est = ReadableTime::parse_asctime("Sun Nov  6 03:49:37 EST 1994").unwrap();
+    assert_eq!(ts(&est), "784111777");
+    assert_eq!(est.time_zone, "EST");
+}
+
+#[test]
+fn strptime_knows_obsolete_zones() {
+    let rt = ReadableTime::parse("1994-11-06 03:49:37 EST", "%F %T %Z").unwrap();
+    assert_eq!(ts(&rt), "784111777");
+}
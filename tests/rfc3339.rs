@@ -0,0 +1,139 @@
+use readable_time::*;
+
+const KATHMANDU: i32 = 5 * 3600 + 45 * 60;
+
+#[test]
+fn formatting() {
+    let rt = ReadableTime::from_timestamp_with_offset(1_764_464_940, KATHMANDU).unwrap();
+    assert_eq!(rt.to_rfc3339().unwrap(), "2025-11-30T06:54:00+05:45");
+    assert_eq!(
+        rt.to_rfc3339_opts(3, true).unwrap(),
+        "2025-11-30T06:54:00.000+05:45"
+    );
+
+    let utc = ReadableTime::utc_from_timestamp(1_764_464_940).unwrap();
+    assert_eq!(utc.to_rfc3339().unwrap(), "2025-11-30T01:09:00Z");
+    assert_eq!(
+        utc.to_rfc3339_opts(0, false).unwrap(),
+        "2025-11-30T01:09:00+00:00"
+    );
+
+    let west = ReadableTime::from_timestamp_with_offset(0, -(3 * 3600 + 30 * 60)).unwrap();
+    assert_eq!(west.to_rfc3339().unwrap(), "1969-12-31T20:30:00-03:30");
+}
+
+#[test]
+fn years_outside_rfc3339() {
+    let first = ReadableTime::utc_from_timestamp(-62_167_219_200).unwrap();
+    assert_eq!(first.to_rfc3339().unwrap(), "0000-01-01T00:00:00Z");
+    let last = ReadableTime::utc_from_timestamp(253_402_300_799).unwrap();
+    assert_eq!(last.to_rfc3339().unwrap(), "9999-12-31T23:59:59Z");
+
+    for timestamp in [-62_167_219_201, -70_000_000_000, 253_402_300_800] {
+        let rt = ReadableTime::utc_from_timestamp(timestamp).unwrap();
+        assert_eq!(
+            rt.to_rfc3339(),
+            Err(ReadableTimeError::OutOfRange),
+            "{timestamp}"
+        );
+        assert_eq!(
+            rt.to_rfc3339_opts(3, false),
+            Err(ReadableTimeError::OutOfRange)
+        );
+    }
+}
+
+/// (input, expected utc rfc3339) for inputs that must be accepted
+const RFC3339_VALID: &[(&str, &str)] = &[
+    ("2025-11-30T06:54:00+05:45", "2025-11-30T01:09:00Z"),
+    ("1985-04-12T23:20:50.52Z", "1985-04-12T23:20:50Z"),
+    ("1996-12-19T16:39:57-08:00", "1996-12-20T00:39:57Z"),
+    ("1990-12-31T23:59:60Z", "1991-01-01T00:00:00Z"),
+    ("1937-01-01T12:00:27.87+00:20", "1937-01-01T11:40:27Z"),
+    ("2025-11-30t06:54:00z", "2025-11-30T06:54:00Z"),
+    ("2025-11-30 06:54:00+00:00", "2025-11-30T06:54:00Z"),
+    ("2024-02-29T00:00:00Z", "2024-02-29T00:00:00Z"),
+];
+
+/// (input, byte position of the error)
+const RFC3339_INVALID: &[(&str, usize)] = &[
+    ("2025-11-30", 10),
+    ("2025-11-30T06:54Z", 16),
+    ("2025-11-30T06:54:00", 19),
+    ("2025-11-30T06:54:00+0545", 22),
+    ("2025-13-30T06:54:00Z", 5),
+    ("2025-02-29T06:54:00Z", 8),
+    ("2025-11-30T24:00:00Z", 11),
+    ("2025-11-30T06:54:00.Z", 20),
+    ("25-11-30T06:54:00Z", 0),
+    ("2025-11-30T06:54:00Zjunk", 20),
+];
+
+#[test]
+fn rfc3339_conformance() {
+    for (input, expected) in RFC3339_VALID {
+        let rt = ReadableTime::parse_rfc3339(input).unwrap_or_else(|e| panic!("{input}: {e}"));
+        let utc = ReadableTime::utc_from_timestamp(rt.format("%s").unwrap().parse().unwrap());
+        assert_eq!(&utc.unwrap().to_rfc3339().unwrap(), expected, "{input}");
+    }
+    for (input, position) in RFC3339_INVALID {
+        let err = match ReadableTime::parse_rfc3339(input).unwrap_err() {
+            ReadableTimeError::Parse(e) => e,
+            e => panic!("{input}: {e}"),
+        };
+        assert_eq!(err.position, *position, "{input}: {err}");
+    }
+}
+
+#[test]
+fn round_trip_keeps_offset() {
+    let rt = ReadableTime::parse_rfc3339("2025-11-30T06:54:00+05:45").unwrap();
+    assert_eq!(rt.utc_offset_seconds, KATHMANDU);
+    assert_eq!(rt.get_timef(), "2025-11-30 06:54:00");
+    assert_eq!(rt.to_rfc3339().unwrap(), "2025-11-30T06:54:00+05:45");
+}
+
+/// (input, expected rfc3339)
+const ISO8601_VALID: &[(&str, &str)] = &[
+    ("2025-11-30", "2025-11-30T00:00:00Z"),
+    ("20251130", "2025-11-30T00:00:00Z"),
+    ("2025-11", "2025-11-01T00:00:00Z"),
+    ("2025-W48-7", "2025-11-30T00:00:00Z"),
+    ("2025W487", "2025-11-30T00:00:00Z"),
+    ("2025-W48", "2025-11-24T00:00:00Z"),
+    ("2020-W53-5", "2021-01-01T00:00:00Z"),
+    ("2026-W01-1", "2025-12-29T00:00:00Z"),
+    ("2025-334", "2025-11-30T00:00:00Z"),
+    ("2024366", "2024-12-31T00:00:00Z"),
+    ("2025-11-30T06:54:00+05:45", "2025-11-30T06:54:00+05:45"),
+    ("20251130T065400+0545", "2025-11-30T06:54:00+05:45"),
+    ("2025-11-30T06:54+05", "2025-11-30T06:54:00+05:00"),
+    ("2025-11-30T06", "2025-11-30T06:00:00Z"),
+    ("2025-11-30T06:54:00,123Z", "2025-11-30T06:54:00Z"),
+    ("2025-11-30T24:00:00", "2025-12-01T00:00:00Z"),
+    ("2025-W48-7T0654-0330", "2025-11-30T06:54:00-03:30"),
+];
+
+const ISO8601_INVALID: &[&str] = &[
+    "2025",
+    "2025-1130",
+    "2025-W54-1",
+    "2025-W53-1",
+    "2025-W48-8",
+    "2025-366",
+    "2025-11-31",
+    "2025-11-30T24:30",
+    "2025-11-30T06:54:00+24:00",
+    "2025-11-30T06:54:00 junk",
+];
+
+#[test]
+fn iso8601_conformance() {
+    for (input, expected) in ISO8601_VALID {
+        let rt = ReadableTime::parse_iso8601(input).unwrap_or_else(|e| panic!("{input}: {e}"));
+        assert_eq!(&rt.to_rfc3339().unwrap(), expected, "{input}");
+    }
+    for input in ISO8601_INVALID {
+        assert!(ReadableTime::parse_iso8601(input).is_err(), "{input}");
+    }
+}
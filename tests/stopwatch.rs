@@ -0,0 +1,113 @@
+use readable_time::*;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+const TICK: Duration = Duration::from_millis(20);
+
+#[test]
+fn elapsed_format() {
+    let table = [
+        (Duration::from_micros(850), "850µs"),
+        (Duration::from_millis(350), "350ms"),
+        (Duration::from_millis(1240), "1.24s"),
+        (Duration::from_millis(1009), "1.00s"),
+        (Duration::from_millis(59_999), "59.99s"),
+        (Duration::from_secs(125), "2m 5s"),
+        (Duration::from_secs(3780 + 59), "1h 3m"),
+        (Duration::ZERO, "0µs"),
+    ];
+    for (d, expected) in table {
+        assert_eq!(format_elapsed(d), expected, "{d:?}");
+    }
+}
+
+#[test]
+fn stop_and_resume() {
+    let mut sw = Stopwatch::new();
+    assert!(!sw.is_running());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+
+    sw.start();
+    sleep(TICK);
+    let first = sw.stop();
+    assert!(first >= TICK);
+    assert!(!sw.is_running());
+
+    // time while stopped is not counted
+    sleep(TICK);
+    assert_eq!(sw.elapsed(), first);
+    assert_eq!(sw.stop(), first);
+
+    sw.resume();
+    assert!(sw.is_running());
+    sleep(TICK);
+    assert!(sw.stop() >= first + TICK);
+
+    sw.reset();
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+}
+
+#[test]
+fn start_resets() {
+    let mut sw = Stopwatch::start_new();
+    sleep(TICK);
+    sw.lap();
+    sw.start();
+    assert!(sw.laps().is_empty());
+    assert!(sw.elapsed() < TICK);
+}
+
+#[test]
+fn laps_add_up() {
+    let mut sw = Stopwatch::start_new();
+    sleep(TICK);
+    let one = sw.lap();
+    sleep(TICK);
+    let two = sw.lap();
+    let total = sw.stop();
+
+    assert!(one >= TICK && two >= TICK);
+    assert_eq!(sw.laps(), &[one, two]);
+    assert!(one + two <= total);
+
+    let report = sw.report();
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[1],
+        format!(
+            "lap 2: {} (total {})",
+            format_elapsed(two),
+            format_elapsed(one + two)
+        )
+    );
+}
+
+#[test]
+fn step_timer_sink() {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    {
+        let lines = Arc::clone(&lines);
+        let _step =
+            StepTimer::new("load").with_sink(move |l| lines.lock().unwrap().push(l.to_string()));
+        sleep(TICK);
+    }
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines.len(), 1);
+    let took = lines[0]
+        .strip_prefix("step load took ")
+        .unwrap_or_else(|| panic!("{}", lines[0]));
+    // a loaded machine may take longer, never less
+    assert!(parse_duration(took).unwrap() >= TICK, "{}", lines[0]);
+}
+
+#[test]
+fn step_timer_finish_reports_once() {
+    let count = Arc::new(Mutex::new(0));
+    let c = Arc::clone(&count);
+    let step = StepTimer::new("parse").with_sink(move |_| *c.lock().unwrap() += 1);
+    let took = step.finish();
+    assert!(took < Duration::from_secs(1));
+    assert_eq!(*count.lock().unwrap(), 1);
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use readable_time::*;
+
+fn utc(secs: u64, nanos: u32) -> ReadableTime {
+    let st = UNIX_EPOCH + Duration::new(secs, nanos);
+    ReadableTime::from_system_time_in(st, &Zone::Utc).unwrap()
+}
+
+#[test]
+fn keeps_nanoseconds() {
+    let rt = utc(1_764_464_940, 123_456_789);
+    assert_eq!(rt.nanosecond, 123_456_789);
+    assert_eq!(rt.millisecond(), 123);
+    assert_eq!(rt.microsecond(), 123_456);
+    assert_eq!(rt.get_timef(), "2025-11-30 01:09:00");
+    assert_eq!(rt.get_timef_ms(), "2025-11-30 01:09:00.123");
+    assert_eq!(rt.get_timef_us(), "2025-11-30 01:09:00.123456");
+    assert_eq!(rt.get_timef_ns(), "2025-11-30 01:09:00.123456789");
+    assert_eq!(
+        rt.format("%S.%N %1N %-N").unwrap(),
+        "00.123456789 1 123456789"
+    );
+    assert_eq!(
+        rt.to_rfc3339_opts(3, true).unwrap(),
+        "2025-11-30T01:09:00.123Z"
+    );
+    assert_eq!(utc(0, 5_000).format("%N %6N").unwrap(), "000005000 000005");
+}
+
+#[test]
+fn before_epoch_is_floored() {
+    let st = UNIX_EPOCH - Duration::from_millis(250);
+    let rt = ReadableTime::from_system_time_in(st, &Zone::Utc).unwrap();
+    assert_eq!(rt.get_timef_ms(), "1969-12-31 23:59:59.750");
+}
+
+#[test]
+fn now_has_sub_second_order() {
+    let a = get_readable_time_utc().unwrap();
+    let b = get_readable_time_utc().unwrap();
+    assert!(a <= b);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    assert!(now.as_secs() as i64 - a.format("%s").unwrap().parse::<i64>().unwrap() < 5);
+}
+
+#[test]
+fn ordering_uses_nanoseconds() {
+    let a = utc(100, 1);
+    let b = utc(100, 2);
+    assert!(a < b);
+    assert_ne!(a, b);
+    assert!(!a.eq_civil(&b));
+}
+
+#[test]
+fn arithmetic_carries_nanoseconds() {
+    let rt = utc(100, 900_000_000);
+    let later = rt.add_duration(Duration::from_millis(250)).unwrap();
+    assert_eq!(later.get_timef_ms(), "1970-01-01 00:01:41.150");
+    let back = later.sub_duration(Duration::from_millis(250)).unwrap();
+    assert_eq!(back, rt);
+    let earlier = rt.sub_duration(Duration::new(1, 950_000_000)).unwrap();
+    assert_eq!(earlier.get_timef_ms(), "1970-01-01 00:01:38.950");
+    assert_eq!(rt.add_days(1).unwrap().nanosecond, 900_000_000);
+    assert_eq!(rt.to_utc().unwrap().nanosecond, 900_000_000);
+}
+
+#[test]
+fn parsers_accept_fractions() {
+    let rt = ReadableTime::parse_timef("2025-01-01 03:04:05.25").unwrap();
+    assert_eq!(rt.nanosecond, 250_000_000);
+    let rt = ReadableTime::parse("03:04:05,123456789123", "%T").unwrap();
+    assert_eq!(rt.nanosecond, 123_456_789);
+    let rt = ReadableTime::parse("05.042", "%S.%3N").unwrap();
+    assert_eq!(rt.millisecond(), 42);
+    assert!(ReadableTime::parse("05.04", "%S.%3N").is_err());
+    let rt = ReadableTime::parse("05.5", "%S.%N").unwrap();
+    assert_eq!(rt.nanosecond, 500_000_000);
+    assert!(ReadableTime::parse_timef("2025-01-01 03:04:05.").is_err());
+
+    let rt = ReadableTime::parse_rfc3339("1985-04-12T23:20:50.52Z").unwrap();
+    assert_eq!(rt.nanosecond, 520_000_000);
+    assert_eq!(
+        rt.to_rfc3339_opts(2, true).unwrap(),
+        "1985-04-12T23:20:50.52Z"
+    );
+    let rt = ReadableTime::parse_iso8601("20251130T071400,000001+0545").unwrap();
+    assert_eq!(rt.nanosecond, 1_000);
+    assert!(ReadableTime::parse_iso8601("2025-11-30T24:00:00.5").is_err());
+
+    let round = ReadableTime::parse_timef(&utc(1_764_464_940, 7).get_timef_ns()).unwrap();
+    assert_eq!(round, utc(1_764_464_940, 7));
+}
@@ -0,0 +1,64 @@
+use readable_time::*;
+use std::thread;
+
+fn is_consistent(rt: &ReadableTime) -> bool {
+    let days_in_month = match rt.month {
+        Month::February if rt.year % 4 == 0 && (rt.year % 100 != 0 || rt.year % 400 == 0) => 29,
+        Month::February => 28,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        _ => 31,
+    };
+    let hour_12 = match rt.hour_24 {
+        0 => 12,
+        13..=23 => rt.hour_24 - 12,
+        h => h,
+    };
+    (1..=days_in_month).contains(&rt.day)
+        && (0..=23).contains(&rt.hour_24)
+        && rt.hour_12 == hour_12
+        && (0..=59).contains(&rt.minute)
+        && (0..=60).contains(&rt.second)
+        && !rt.time_zone.is_empty()
+}
+
+#[test]
+fn get_readable_time_from_many_threads() {
+    let handles: Vec<_> = (0..16)
+        .map(|_| {
+            thread::spawn(|| {
+                for _ in 0..2000 {
+                    let rt = get_readable_time().unwrap();
+                    assert!(is_consistent(&rt), "{rt:?}");
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+}
+
+#[test]
+fn different_timestamps_do_not_mix() {
+    let stamps: Vec<time_t> = (0..64).map(|i| i * 7_919_993 - 200_000_000).collect();
+    let expected: Vec<String> = stamps
+        .iter()
+        .map(|&t| ReadableTime::from_timestamp(t).unwrap().get_timef())
+        .collect();
+
+    thread::scope(|s| {
+        for n in 0..16 {
+            let (stamps, expected) = (&stamps, &expected);
+            s.spawn(move || {
+                for round in 0..500 {
+                    let i = (n * 7 + round) % stamps.len();
+                    let rt = ReadableTime::from_timestamp(stamps[i]).unwrap();
+                    assert!(is_consistent(&rt), "{rt:?}");
+                    assert_eq!(rt.get_timef(), expected[i]);
+                    let utc = ReadableTime::utc_from_timestamp(stamps[i]).unwrap();
+                    assert!(is_consistent(&utc), "{utc:?}");
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,49 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use readable_time::*;
+
+// these go through the host zone, so only zone independent parts are checked
+
+#[test]
+fn negative_timestamp() {
+    let rt = ReadableTime::from_timestamp(-86_400 - 1).unwrap();
+    assert_eq!(rt.format("%s").unwrap(), "-86401");
+    assert_eq!(rt.nanosecond, 0);
+    assert_eq!(rt.to_utc().unwrap().get_timef(), "1969-12-30 23:59:59");
+    assert_eq!(rt.zone.offset_at(-86_401).unwrap(), rt.utc_offset_seconds);
+}
+
+#[test]
+fn system_time_before_epoch() {
+    let st = UNIX_EPOCH - Duration::new(86_400, 250_000_000);
+    let rt = ReadableTime::from_system_time(st).unwrap();
+    // floored to the second before, like a negative timestamp
+    assert_eq!(rt.format("%s").unwrap(), "-86401");
+    assert_eq!(rt.nanosecond, 750_000_000);
+    assert_eq!(
+        rt.to_utc().unwrap().get_timef_ms(),
+        "1969-12-30 23:59:59.750"
+    );
+}
+
+#[test]
+fn system_time_out_of_range() {
+    let st = UNIX_EPOCH + Duration::from_secs(i64::MAX as u64 / 2);
+    assert_eq!(
+        ReadableTime::from_system_time(st).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    assert_eq!(
+        ReadableTime::from_system_time_in(st, &Zone::Utc).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    let st = UNIX_EPOCH - Duration::from_secs(i64::MAX as u64 / 2);
+    assert_eq!(
+        ReadableTime::from_system_time(st).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+    assert_eq!(
+        ReadableTime::from_timestamp(time_t::MAX).unwrap_err(),
+        ReadableTimeError::OutOfRange
+    );
+}
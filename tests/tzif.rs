@@ -0,0 +1,115 @@
+use readable_time::*;
+
+mod common;
+use common::{FIXTURES, fixture};
+
+#[test]
+fn kathmandu() {
+    let tz = fixture("Asia/Kathmandu");
+    assert_eq!(tz.transitions().len(), 3);
+    assert_eq!(tz.footer(), Some("<+0545>-5:45"));
+
+    let current = tz.local_time_type(1_764_464_940);
+    assert_eq!(current.utc_offset, 5 * 3600 + 45 * 60);
+    assert!(!current.is_dst);
+    assert_eq!(current.abbreviation, "+0545");
+
+    let rt = ReadableTime::from_timestamp_in(1_764_464_940, &tz).unwrap();
+    assert_eq!(
+        rt.get_extended_ptimef().unwrap(),
+        "Sun Nov 30 06:54:00 +0545 2025"
+    );
+
+    // +0530 before 1986
+    let rt = ReadableTime::from_timestamp_in(0, &tz).unwrap();
+    assert_eq!(rt.get_timef(), "1970-01-01 05:30:00");
+    assert_eq!(rt.time_zone, "+0530");
+}
+
+#[test]
+fn new_york_dst() {
+    let tz = fixture("America/New_York");
+    assert_eq!(tz.footer(), Some("EST5EDT,M3.2.0,M11.1.0"));
+
+    let winter = ReadableTime::from_timestamp_in(1_705_320_000, &tz).unwrap();
+    assert_eq!(winter.get_timef(), "2024-01-15 07:00:00");
+    assert_eq!(winter.time_zone, "EST");
+
+    let summer = ReadableTime::from_timestamp_in(1_720_094_400, &tz).unwrap();
+    assert_eq!(summer.get_timef(), "2024-07-04 08:00:00");
+    assert_eq!(summer.time_zone, "EDT");
+    assert!(tz.local_time_type(1_720_094_400).is_dst);
+
+    // 2024-03-10 02:00 EST jumps to 03:00 EDT
+    let before = ReadableTime::from_timestamp_in(1_710_053_999, &tz).unwrap();
+    let after = ReadableTime::from_timestamp_in(1_710_054_000, &tz).unwrap();
+    assert_eq!(before.get_timef(), "2024-03-10 01:59:59");
+    assert_eq!(after.get_timef(), "2024-03-10 03:00:00");
+}
+
+#[test]
+fn before_first_transition_uses_first_type() {
+    let tz = fixture("America/New_York");
+    let lmt = tz.local_time_type(-3_000_000_000);
+    assert_eq!(lmt.abbreviation, "LMT");
+    assert_eq!(lmt.utc_offset, -17_762);
+}
+
+#[test]
+fn utc_has_no_transitions() {
+    let tz = fixture("Etc/UTC");
+    assert!(tz.transitions().is_empty());
+    assert_eq!(tz.footer(), Some("UTC0"));
+    let rt = ReadableTime::from_timestamp_in(0, &tz).unwrap();
+    assert_eq!(rt.get_timef(), "1970-01-01 00:00:00");
+    assert_eq!(rt.time_zone, "UTC");
+}
+
+#[test]
+fn version_1_data() {
+    let bytes = std::fs::read(format!("{FIXTURES}/Asia/Kathmandu")).unwrap();
+    // header (44 bytes) + 3 transitions, 3 types and 16 abbreviation chars
+    let mut v1 = bytes[..44 + 3 * 4 + 3 + 3 * 6 + 16].to_vec();
+    v1[4] = 0;
+    let tz = TimeZone::from_tzif("Asia/Kathmandu", &v1).unwrap();
+    assert_eq!(tz.footer(), None);
+    assert_eq!(tz.local_time_type(1_764_464_940).abbreviation, "+0545");
+    assert_eq!(tz.local_time_type(0).abbreviation, "+0530");
+}
+
+#[test]
+fn invalid_data() {
+    let bytes = std::fs::read(format!("{FIXTURES}/America/New_York")).unwrap();
+    assert!(TimeZone::from_tzif("x", b"").is_err());
+    assert!(TimeZone::from_tzif("x", b"TZof2").is_err());
+    assert!(TimeZone::from_tzif("x", &bytes[..bytes.len() - 10]).is_err());
+    assert!(TimeZone::from_tzif("x", &bytes[..100]).is_err());
+}
+
+#[test]
+fn named_uses_tzdir() {
+    // SAFETY: no other test in this binary reads the environment
+    unsafe { std::env::set_var("TZDIR", FIXTURES) };
+    let tz = TimeZone::named("Asia/Kathmandu").unwrap();
+    assert_eq!(tz.name(), "Asia/Kathmandu");
+    assert!(TimeZone::named("../zoneinfo/Asia/Kathmandu").is_err());
+    assert!(TimeZone::named("Mars/Olympus_Mons").is_err());
+}
+
+#[test]
+fn offset_dst_and_day_of_year() {
+    let tz = fixture("America/New_York");
+    let summer = ReadableTime::from_timestamp_in(1_720_094_400, &tz).unwrap();
+    assert_eq!(summer.utc_offset_seconds, -4 * 3600);
+    assert!(summer.is_dst);
+    assert_eq!(summer.day_of_year, 186);
+    assert_eq!(
+        summer.get_extended_ptimef().unwrap(),
+        "Thu Jul 4 08:00:00 -0400 2024"
+    );
+
+    let winter = ReadableTime::from_timestamp_in(1_705_320_000, &tz).unwrap();
+    assert_eq!(winter.get_offsetf(), "-0500");
+    assert!(!winter.is_dst);
+    assert_eq!(winter.day_of_year, 15);
+}
@@ -0,0 +1,24 @@
+use readable_time::*;
+
+fn check(t: time_t, expected: &str, week_day: Weekday) {
+    let rt = ReadableTime::utc_from_timestamp(t).unwrap();
+    assert_eq!(rt.get_timef(), expected, "timestamp {t}");
+    assert_eq!(rt.week_day, week_day, "timestamp {t}");
+    assert_eq!(rt.time_zone, "UTC");
+}
+
+#[test]
+fn known_timestamps() {
+    check(0, "1970-01-01 00:00:00", Weekday::Thursday);
+    check(1_764_485_640, "2025-11-30 06:54:00", Weekday::Sunday);
+    check(951_782_400, "2000-02-29 00:00:00", Weekday::Tuesday);
+    check(-1, "1969-12-31 23:59:59", Weekday::Wednesday);
+    check(-2_208_988_800, "1900-01-01 00:00:00", Weekday::Monday);
+    check(2_147_483_648, "2038-01-19 03:14:08", Weekday::Tuesday);
+}
+
+#[test]
+fn now_is_utc() {
+    let rt = get_readable_time_utc().unwrap();
+    assert_eq!(rt.time_zone, "UTC");
+}
@@ -0,0 +1,82 @@
+use readable_time::*;
+
+fn utc(date: &str) -> ReadableTime {
+    ReadableTime::parse(date, "%Y-%m-%d").unwrap()
+}
+
+// expected values from GNU date: `date -u -d <date> '+%U %W %V %G %g'`
+const TABLE: [(&str, u32, u32, u32, i32); 11] = [
+    ("2024-12-29", 52, 52, 52, 2024),
+    ("2024-12-30", 52, 53, 1, 2025),
+    ("2021-01-03", 1, 0, 53, 2020),
+    ("2021-01-04", 1, 1, 1, 2021),
+    ("2026-01-01", 0, 0, 1, 2026),
+    ("2027-01-01", 0, 0, 53, 2026),
+    ("2027-01-03", 1, 0, 53, 2026),
+    ("2025-11-30", 48, 47, 48, 2025),
+    ("2023-01-01", 1, 0, 52, 2022),
+    ("2024-01-01", 0, 1, 1, 2024),
+    ("2020-12-31", 52, 52, 53, 2020),
+];
+
+#[test]
+fn week_numbers() {
+    for (date, sunday, monday, iso, iso_year) in TABLE {
+        let rt = utc(date);
+        assert_eq!(rt.sunday_week(), sunday, "{date}");
+        assert_eq!(rt.monday_week(), monday, "{date}");
+        assert_eq!(rt.iso_week(), (iso_year, iso, rt.week_day), "{date}");
+        assert_eq!(
+            rt.format("%U %W %V %G %g").unwrap(),
+            format!(
+                "{sunday:02} {monday:02} {iso:02} {iso_year} {:02}",
+                iso_year % 100
+            ),
+            "{date}"
+        );
+    }
+}
+
+#[test]
+fn from_iso_week_date() {
+    let rt = ReadableTime::from_iso_week_date(2025, 1, Weekday::Monday, &Zone::Utc).unwrap();
+    assert_eq!(rt.get_timef(), "2024-12-30 00:00:00");
+    let rt = ReadableTime::from_iso_week_date(2020, 53, Weekday::Sunday, &Zone::Utc).unwrap();
+    assert_eq!(rt.get_timef(), "2021-01-03 00:00:00");
+
+    assert_eq!(
+        ReadableTime::from_iso_week_date(2025, 53, Weekday::Monday, &Zone::Utc).unwrap_err(),
+        ReadableTimeError::InvalidIsoWeek {
+            year: 2025,
+            week: 53
+        }
+    );
+    assert_eq!(
+        ReadableTime::from_iso_week_date(2025, 0, Weekday::Monday, &Zone::Utc).unwrap_err(),
+        ReadableTimeError::InvalidIsoWeek {
+            year: 2025,
+            week: 0
+        }
+    );
+}
+
+#[test]
+fn round_trip() {
+    // 1999-12-27 .. 2031-01-05, covers 52 and 53 week years and every year boundary shape
+    let start = ReadableTime::parse("1999-12-27", "%Y-%m-%d").unwrap();
+    for n in 0..11_333 {
+        let rt = start.add_days(n).unwrap();
+        let (year, week, weekday) = rt.iso_week();
+        let back = ReadableTime::from_iso_week_date(year, week, weekday, &Zone::Utc).unwrap();
+        assert_eq!(back, rt, "{}", rt.get_timef());
+    }
+}
+
+#[test]
+fn iso_week_date_in_zone() {
+    let offset = 5 * 3600 + 45 * 60;
+    let rt =
+        ReadableTime::from_iso_week_date(2025, 48, Weekday::Sunday, &Zone::Fixed(offset)).unwrap();
+    assert_eq!(rt.format("%F %T %z").unwrap(), "2025-11-30 00:00:00 +0545");
+    assert_eq!(rt.iso_week(), (2025, 48, Weekday::Sunday));
+}